@@ -1 +1,3 @@
+pub mod solutions;
 pub mod template;
+pub mod utils;
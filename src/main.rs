@@ -1,53 +1,149 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::solutions;
+use advent_of_code::template::commands::{
+    all, download, examples, generate, read, scaffold, solve, trace, verify, visualize,
+};
+use advent_of_code::template::default_year;
 use args::{parse, AppArguments};
 
 mod args {
+    use advent_of_code::template::{baseline::BaselineOptions, report::OutputFormat};
+    use advent_of_code::utils::visualize::Format;
     use std::process;
 
     pub enum AppArguments {
         Download {
+            year: Option<u16>,
             day: u8,
         },
+        Examples {
+            year: Option<u16>,
+            day: u8,
+        },
+        Generate {
+            year: Option<u16>,
+            day: u8,
+            seed: u64,
+            size: usize,
+            time: bool,
+            format: OutputFormat,
+        },
         Read {
+            year: Option<u16>,
             day: u8,
         },
         Scaffold {
+            year: Option<u16>,
             day: u8,
+            example: Option<String>,
         },
         Solve {
+            year: Option<u16>,
             day: u8,
-            release: bool,
             time: bool,
             submit: Option<u8>,
+            format: OutputFormat,
+            baseline: BaselineOptions,
         },
         All {
-            release: bool,
+            year: Option<u16>,
             time: bool,
+            format: OutputFormat,
+            baseline: BaselineOptions,
+        },
+        Verify {
+            year: Option<u16>,
+        },
+        Trace {
+            year: Option<u16>,
+            day: u8,
+            part: u8,
+            input: Option<String>,
+            output: Option<String>,
+            delay: u64,
+        },
+        Visualize {
+            year: Option<u16>,
+            day: u8,
+            part: u8,
+            input: Option<String>,
+            output: Option<String>,
+            format: Option<Format>,
         },
     }
 
+    fn parse_baseline_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BaselineOptions, pico_args::Error> {
+        let defaults = BaselineOptions::default();
+        Ok(BaselineOptions {
+            save: args.contains("--save-baseline"),
+            threshold: args
+                .opt_value_from_str("--threshold")?
+                .unwrap_or(defaults.threshold),
+        })
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                year: args.opt_value_from_str("--year")?,
                 time: args.contains("--time"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                baseline: parse_baseline_options(&mut args)?,
             },
             Some("download") => AppArguments::Download {
+                year: args.opt_value_from_str("--year")?,
+                day: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
+                year: args.opt_value_from_str("--year")?,
+                day: args.free_from_str()?,
+            },
+            Some("generate") => AppArguments::Generate {
+                year: args.opt_value_from_str("--year")?,
+                seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+                size: args.opt_value_from_str("--size")?.unwrap_or(100),
+                time: args.contains("--time"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
+                year: args.opt_value_from_str("--year")?,
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
+                year: args.opt_value_from_str("--year")?,
+                example: args.opt_value_from_str("--example")?,
                 day: args.free_from_str()?,
             },
             Some("solve") => AppArguments::Solve {
+                year: args.opt_value_from_str("--year")?,
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                baseline: parse_baseline_options(&mut args)?,
+            },
+            Some("verify") => AppArguments::Verify {
+                year: args.opt_value_from_str("--year")?,
+            },
+            Some("trace") => AppArguments::Trace {
+                year: args.opt_value_from_str("--year")?,
+                part: args.opt_value_from_str("--part")?.unwrap_or(1),
+                input: args.opt_value_from_str("--input")?,
+                output: args.opt_value_from_str("--output")?,
+                delay: args.opt_value_from_str("--delay")?.unwrap_or(100),
+                day: args.free_from_str()?,
+            },
+            Some("visualize") => AppArguments::Visualize {
+                year: args.opt_value_from_str("--year")?,
+                part: args.opt_value_from_str("--part")?.unwrap_or(1),
+                input: args.opt_value_from_str("--input")?,
+                output: args.opt_value_from_str("--output")?,
+                format: args.opt_value_from_str("--format")?,
+                day: args.free_from_str()?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -68,6 +164,22 @@ mod args {
     }
 }
 
+/// Year of a command that works on a single day: `--year`, falling back to `AOC_YEAR`.
+fn resolve_year(year: Option<u16>) -> u16 {
+    match year.or_else(default_year) {
+        Some(year) => year,
+        None => {
+            eprintln!("No year given. Pass `--year` or set `AOC_YEAR` in `.cargo/config.toml`.");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Years of a command that works on many days: `--year`, falling back to all years with solutions.
+fn resolve_years(year: Option<u16>) -> Vec<u16> {
+    year.map_or_else(solutions::years, |year| vec![year])
+}
+
 fn main() {
     match parse() {
         Err(err) => {
@@ -75,16 +187,52 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                time,
+                format,
+                baseline,
+            } => all::handle(&resolve_years(year), time, format, baseline),
+            AppArguments::Download { year, day } => download::handle(resolve_year(year), day),
+            AppArguments::Examples { year, day } => examples::handle(resolve_year(year), day),
+            AppArguments::Generate {
+                year,
+                day,
+                seed,
+                size,
+                time,
+                format,
+            } => generate::handle(resolve_year(year), day, seed, size, time, format),
+            AppArguments::Read { year, day } => read::handle(resolve_year(year), day),
+            AppArguments::Scaffold { year, day, example } => match example {
+                Some(name) => scaffold::handle_example(resolve_year(year), day, &name),
+                None => scaffold::handle(resolve_year(year), day),
+            },
             AppArguments::Solve {
+                year,
                 day,
-                release,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                format,
+                baseline,
+            } => solve::handle(resolve_year(year), day, time, submit, format, baseline),
+            AppArguments::Verify { year } => verify::handle(&resolve_years(year)),
+            AppArguments::Trace {
+                year,
+                day,
+                part,
+                input,
+                output,
+                delay,
+            } => trace::handle(resolve_year(year), day, part, input, output, delay),
+            AppArguments::Visualize {
+                year,
+                day,
+                part,
+                input,
+                output,
+                format,
+            } => visualize::handle(resolve_year(year), day, part, input, output, format),
         },
     };
 }
@@ -0,0 +1,27 @@
+/// Registry of all seasons, each holding the solved days of one year.
+/// New seasons are added here by `cargo scaffold`, which appends to the module list and `YEARS`.
+use crate::template::Day;
+
+pub mod year2023;
+
+pub static YEARS: &[&[&dyn Day]] = &[year2023::DAYS];
+
+/// All solved days of all seasons.
+pub fn all() -> impl Iterator<Item = &'static dyn Day> {
+    YEARS.iter().flat_map(|days| days.iter().copied())
+}
+
+/// Years that have at least one solved day, in ascending order.
+#[must_use]
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = all().map(|solution| solution.year()).collect();
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+/// Look up the solution for a given day, if it has been scaffolded.
+#[must_use]
+pub fn get(year: u16, day: u8) -> Option<&'static dyn Day> {
+    all().find(|solution| solution.year() == year && solution.day() == day)
+}
@@ -0,0 +1,215 @@
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::Rng;
+#[cfg(test)]
+use fxhash::FxHashMap as HashMap;
+#[cfg(test)]
+use regex::Regex;
+
+fn find_num(bad_calibration: &str) -> Option<u32> {
+    let tens = bad_calibration.chars().find_map(|x| x.to_digit(10))?;
+    let ones = bad_calibration.chars().rev().find_map(|x| x.to_digit(10))?;
+    Some(tens * 10 + ones)
+}
+
+fn wtf_replace(line: &str) -> Option<u32> {
+    let wtf = line
+        .replace("one", "o1e")
+        .replace("two", "t2o")
+        .replace("three", "th3ee")
+        .replace("four", "4")
+        .replace("five", "fi5e")
+        .replace("six", "6")
+        .replace("seven", "se7en")
+        .replace("eight", "ei8ht")
+        .replace("nine", "ni9e");
+
+    find_num(&wtf)
+}
+
+/// Sum of the calibration values `calibration` finds in each line.
+fn calibrate(input: &str, calibration: impl Fn(&str) -> Option<u32>) -> Result<Option<u32>> {
+    input
+        .trim_end()
+        .split('\n')
+        .map(|line| calibration(line).ok_or_else(|| Error::at(input, line, "line has no digit")))
+        .sum::<Result<u32>>()
+        .map(Some)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day01::parse(input).and_then(|input| Day01::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day01::parse(input).and_then(|input| Day01::part_two(&input))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        calibrate(input, find_num)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        calibrate(input, wtf_replace)
+    }
+
+    /// `size` lines of letters, digits and spelled out digits, with at least one digit each. Spelled out digits
+    /// often share letters, like `eightwo`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const WORDS: [&str; 9] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        const LETTERS: &[u8] = b"efghinorstuvwx";
+
+        let lines: Vec<String> = (0..size)
+            .map(|_| {
+                let tokens = rng.range(1..9);
+                let digit = rng.range(0..tokens);
+                let mut line = String::new();
+                for i in 0..tokens {
+                    if i == digit || rng.chance(0.2) {
+                        line += &rng.range(1..10).to_string();
+                    } else if rng.chance(0.6) {
+                        let word = rng.choose(&WORDS);
+                        // let neighbouring names share a letter where they can, like `sevenine`
+                        match word.strip_prefix(line.chars().last().unwrap_or(' ')) {
+                            Some(rest) if rng.chance(0.5) => line += rest,
+                            _ => line += word,
+                        }
+                    } else {
+                        line.push(char::from(*rng.choose(LETTERS)));
+                    }
+                }
+                line
+            })
+            .collect();
+
+        Some(lines.join("\n") + "\n")
+    }
+}
+
+/// Part two by matching digits and their names with a regex from both ends, which `wtf_replace` was checked against.
+#[cfg(test)]
+pub struct Day01Reference;
+
+#[cfg(test)]
+impl Day01Reference {
+    fn find_crazy_person_numbers(line: &str, number_words_map: &HashMap<&str, u32>) -> u32 {
+        let regex = Regex::new(r"[0-9]|one|two|three|four|five|six|seven|eight|nine").unwrap();
+        let xeger = Regex::new(r"[0-9]|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin").unwrap();
+        let reversed: String = line.chars().rev().collect();
+        let tens = number_words_map
+            .get(<&str>::from(regex.find(line).unwrap()))
+            .unwrap();
+        let ones = number_words_map
+            .get(<&str>::from(xeger.find(&reversed).unwrap()))
+            .unwrap();
+        tens * 10 + ones
+    }
+
+    fn number_words() -> HashMap<&'static str, u32> {
+        let mut number_words: HashMap<&str, u32> = HashMap::default();
+        number_words.insert("one", 1);
+        number_words.insert("1", 1);
+        number_words.insert("eno", 1);
+        number_words.insert("two", 2);
+        number_words.insert("2", 2);
+        number_words.insert("owt", 2);
+        number_words.insert("three", 3);
+        number_words.insert("3", 3);
+        number_words.insert("eerht", 3);
+        number_words.insert("four", 4);
+        number_words.insert("4", 4);
+        number_words.insert("ruof", 4);
+        number_words.insert("five", 5);
+        number_words.insert("5", 5);
+        number_words.insert("evif", 5);
+        number_words.insert("six", 6);
+        number_words.insert("6", 6);
+        number_words.insert("xis", 6);
+        number_words.insert("seven", 7);
+        number_words.insert("7", 7);
+        number_words.insert("neves", 7);
+        number_words.insert("eight", 8);
+        number_words.insert("8", 8);
+        number_words.insert("thgie", 8);
+        number_words.insert("nine", 9);
+        number_words.insert("9", 9);
+        number_words.insert("enin", 9);
+        number_words
+    }
+}
+
+#[cfg(test)]
+impl Solution for Day01Reference {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(_input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(None)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let number_words = Self::number_words();
+        Ok(Some(
+            input
+                .trim_end()
+                .split("\n")
+                .map(|crazy_number| Self::find_crazy_person_numbers(crazy_number, &number_words))
+                .sum(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 1));
+        assert_eq!(result, Ok(Some(220)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 1));
+        assert_eq!(result, Ok(Some(281)));
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::template::examples::check::<Day01>();
+    }
+
+    #[test]
+    fn test_reference() {
+        crate::template::differential::check::<Day01, Day01Reference>(50, 100);
+    }
+}
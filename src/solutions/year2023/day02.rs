@@ -0,0 +1,132 @@
+use crate::template::{error::Result, Solution};
+use crate::utils::parse::{integer, key_value, lines, parse_all, ParseResult};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::space1;
+use nom::combinator::{map, value};
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, separated_pair};
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: u32,
+    dice: Vec<Vec<Die>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Color {
+    Red,
+    Blue,
+    Green,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Die {
+    count: u32,
+    color: Color,
+}
+
+fn parse_die(input: &str) -> ParseResult<'_, Die> {
+    map(
+        separated_pair(
+            integer,
+            space1,
+            alt((
+                value(Color::Red, tag("red")),
+                value(Color::Blue, tag("blue")),
+                value(Color::Green, tag("green")),
+            )),
+        ),
+        |(count, color)| Die { count, color },
+    )(input)
+}
+
+fn parse_bag_pull(input: &str) -> ParseResult<'_, Vec<Die>> {
+    separated_list1(tag(", "), parse_die)(input)
+}
+
+fn parse_game(input: &str) -> ParseResult<'_, Game> {
+    map(
+        key_value(
+            preceded(tag("Game "), integer),
+            separated_list1(tag("; "), parse_bag_pull),
+        ),
+        |(id, dice)| Game { id, dice },
+    )(input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day02::parse(input).and_then(|input| Day02::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day02::parse(input).and_then(|input| Day02::part_two(&input))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input<'a> = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_all(input, lines(parse_game))
+    }
+
+    fn part_one(games: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(
+            games
+                .iter()
+                .filter(|game| {
+                    game.dice.iter().flatten().all(|dice| match dice.color {
+                        Color::Red => dice.count <= 12,
+                        Color::Green => dice.count <= 13,
+                        Color::Blue => dice.count <= 14,
+                    })
+                })
+                .map(|game| game.id)
+                .sum(),
+        ))
+    }
+
+    fn part_two(games: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Ok(Some(
+            games
+                .iter()
+                .map(|game| {
+                    let mut color_max = (0, 0, 0);
+                    for dice in game.dice.iter().flatten() {
+                        match dice.color {
+                            Color::Red => color_max.0 = color_max.0.max(dice.count),
+                            Color::Green => color_max.1 = color_max.1.max(dice.count),
+                            Color::Blue => color_max.2 = color_max.2.max(dice.count),
+                        }
+                    }
+                    color_max.0 * color_max.1 * color_max.2
+                })
+                .sum(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 2));
+        assert_eq!(result, Ok(Some(8)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 2));
+        assert_eq!(result, Ok(Some(2286)));
+    }
+}
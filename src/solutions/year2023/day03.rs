@@ -0,0 +1,124 @@
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::Grid;
+use std::collections::HashMap;
+
+fn is_symbol(cell: char) -> bool {
+    !cell.is_ascii_digit() && cell != '.'
+}
+
+/// A number in the schematic together with the positions of the symbols around it.
+struct Number {
+    value: u32,
+    symbols: Vec<(usize, usize)>,
+}
+
+fn numbers(grid: &Grid<char>) -> Result<Vec<Number>> {
+    let mut numbers = Vec::new();
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if !row[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+
+            let mut number = Number {
+                value: 0,
+                symbols: Vec::new(),
+            };
+            while let Some(digit) = row.get(x).and_then(|cell| cell.to_digit(10)) {
+                number.value = number
+                    .value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit))
+                    .ok_or_else(|| {
+                        Error::solver(format!("the number ending at {x}, {y} is too large"))
+                    })?;
+                number.symbols.extend(
+                    grid.neighbours_diagonal(x, y)
+                        .filter(|&position| is_symbol(grid[position])),
+                );
+                x += 1;
+            }
+
+            number.symbols.sort_unstable();
+            number.symbols.dedup();
+            numbers.push(number);
+        }
+    }
+    Ok(numbers)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day03::parse(input).and_then(|input| Day03::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day03::parse(input).and_then(|input| Day03::part_two(&input))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input<'a> = Grid<char>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |c| (!c.is_whitespace()).then_some(c))
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(
+            numbers(grid)?
+                .iter()
+                .filter(|number| !number.symbols.is_empty())
+                .map(|number| number.value)
+                .sum(),
+        ))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let mut gears: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+        for number in numbers(grid)? {
+            for &position in &number.symbols {
+                if grid[position] == '*' {
+                    gears.entry(position).or_default().push(number.value);
+                }
+            }
+        }
+
+        // a gear is any `*` next to exactly two numbers
+        Ok(Some(
+            gears
+                .values()
+                .filter(|values| values.len() == 2)
+                .map(|values| values[0] * values[1])
+                .sum(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 3));
+        assert_eq!(result, Ok(Some(4361)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 3));
+        assert_eq!(result, Ok(Some(467835)));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::template::{
+    error::{parse_at, split_once_at, Error, Result},
+    Solution,
+};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+pub struct Card {
+    matches: Vec<u32>,
+}
+
+impl Card {
+    /// Parse `line`, a line of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self> {
+        let (_, numbers) = split_once_at(input, line, ":")?;
+        let (winners, entries) = split_once_at(input, numbers, " | ")?;
+
+        let wins: HashSet<u32> = winners
+            .split_whitespace()
+            .map(|number| parse_at(input, number))
+            .collect::<Result<_>>()?;
+
+        let attempts: HashSet<u32> = entries
+            .split_whitespace()
+            .map(|number| parse_at(input, number))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            matches: wins.intersection(&attempts).cloned().collect(),
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day04::parse(input).and_then(|cards| Day04::part_one(&cards))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day04::parse(input).and_then(|cards| Day04::part_two(&cards))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input<'a> = Vec<Card>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .trim()
+            .par_lines()
+            .map(|line| Card::parse(input, line))
+            .collect()
+    }
+
+    fn part_one(cards: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        cards
+            .iter()
+            .map(|card| match card.matches.len() {
+                0 => Ok(0),
+                matches => 2_u32.checked_pow(matches as u32 - 1).ok_or_else(|| {
+                    Error::solver(format!("{matches} matches are worth too many points"))
+                }),
+            })
+            .sum::<Result<u32>>()
+            .map(Some)
+    }
+
+    fn part_two(cards: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let mut counts: Vec<u32> = vec![1; cards.len()];
+
+        for (i, card) in cards.iter().enumerate() {
+            // cards never make you win cards past the end of the table
+            let update_to = (i + card.matches.len()).min(counts.len() - 1);
+            for j in i + 1..=update_to {
+                counts[j] += counts[i];
+            }
+        }
+        Ok(Some(counts.iter().sum()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 4));
+        assert_eq!(result, Ok(Some(13)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 4));
+        assert_eq!(result, Ok(Some(30)));
+    }
+}
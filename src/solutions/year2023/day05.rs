@@ -0,0 +1,262 @@
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::parse::{integer, integers, labelled, lines, parse_all, ParseResult};
+use crate::utils::{IntervalSet, Rng};
+use nom::character::complete::{line_ending, space1};
+use nom::combinator::map;
+use nom::sequence::{pair, preceded, tuple};
+
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    seed_to_soil: Vec<Mapping>,
+    soil_to_fertilizer: Vec<Mapping>,
+    fertilizer_to_water: Vec<Mapping>,
+    water_to_light: Vec<Mapping>,
+    light_to_temperature: Vec<Mapping>,
+    temperature_to_humidity: Vec<Mapping>,
+    humidity_to_location: Vec<Mapping>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Mapping {
+    source: i64,
+    destination: i64,
+    range: i64,
+}
+
+fn traverse_maps(values: &IntervalSet, mappings: &[Mapping]) -> IntervalSet {
+    values.map_piecewise(mappings.iter().map(|mapping| {
+        (
+            mapping.source..mapping.source + mapping.range,
+            mapping.destination - mapping.source,
+        )
+    }))
+}
+
+impl Almanac {
+    /// Map all seeds through the almanac to their locations, a whole range at a time.
+    fn lowest_location(&self, seeds: IntervalSet) -> Option<u64> {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location,
+        ]
+        .into_iter()
+        .fold(seeds, |values, mappings| traverse_maps(&values, mappings))
+        .min()
+        .map(|location| location as u64)
+    }
+}
+
+fn parse_mapping(input: &str) -> ParseResult<'_, Mapping> {
+    map(
+        tuple((
+            integer,
+            preceded(space1, integer),
+            preceded(space1, integer),
+        )),
+        |(destination, source, range)| Mapping {
+            source,
+            destination,
+            range,
+        },
+    )(input)
+}
+
+/// A map section, after the blank line that separates it from the previous one.
+fn parse_mappings<'a>(name: &'static str) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<Mapping>> {
+    preceded(
+        pair(line_ending, line_ending),
+        labelled(name, lines(parse_mapping)),
+    )
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac> {
+    parse_all(
+        input,
+        map(
+            tuple((
+                labelled("seeds", integers),
+                parse_mappings("seed-to-soil map"),
+                parse_mappings("soil-to-fertilizer map"),
+                parse_mappings("fertilizer-to-water map"),
+                parse_mappings("water-to-light map"),
+                parse_mappings("light-to-temperature map"),
+                parse_mappings("temperature-to-humidity map"),
+                parse_mappings("humidity-to-location map"),
+            )),
+            |(
+                seeds,
+                seed_to_soil,
+                soil_to_fertilizer,
+                fertilizer_to_water,
+                water_to_light,
+                light_to_temperature,
+                temperature_to_humidity,
+                humidity_to_location,
+            )| Almanac {
+                seeds,
+                seed_to_soil,
+                soil_to_fertilizer,
+                fertilizer_to_water,
+                water_to_light,
+                light_to_temperature,
+                temperature_to_humidity,
+                humidity_to_location,
+            },
+        ),
+    )
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>> {
+    Day05::parse(input).and_then(|input| Day05::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>> {
+    Day05::parse(input).and_then(|input| Day05::part_two(&input))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input<'a> = Almanac;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_almanac(input)
+    }
+
+    fn part_one(almanac: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let seeds = almanac.seeds.iter().map(|&seed| seed..seed + 1).collect();
+
+        Ok(almanac.lowest_location(seeds))
+    }
+
+    fn part_two(almanac: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        if almanac.seeds.len() % 2 != 0 {
+            return Err(Error::solver(
+                "seeds do not come in pairs of start and length",
+            ));
+        }
+
+        let seeds = almanac
+            .seeds
+            .chunks_exact(2)
+            .map(|x| x[0]..x[0] + x[1])
+            .collect();
+
+        Ok(almanac.lowest_location(seeds))
+    }
+
+    /// `size` mappings per map, with values up to 2^32 like real inputs, and ten pairs of seeds.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const LIMIT: i64 = 1 << 32;
+        let maps = [
+            "seed-to-soil",
+            "soil-to-fertilizer",
+            "fertilizer-to-water",
+            "water-to-light",
+            "light-to-temperature",
+            "temperature-to-humidity",
+            "humidity-to-location",
+        ];
+
+        let seeds: Vec<_> = (0..10)
+            .map(|_| format!("{} {}", rng.range(0..LIMIT), rng.range(1..LIMIT / 16)))
+            .collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for map in maps {
+            // sorted, distinct bounds paired up give disjoint source ranges
+            let mut bounds: Vec<_> = (0..2 * size.max(1)).map(|_| rng.range(0..LIMIT)).collect();
+            bounds.sort_unstable();
+            bounds.dedup();
+
+            let mut mappings: Vec<_> = bounds
+                .chunks_exact(2)
+                .map(|range| {
+                    let length = range[1] - range[0];
+                    let destination = rng.range(0..LIMIT - length);
+                    format!("{destination} {} {length}", range[0])
+                })
+                .collect();
+            rng.shuffle(&mut mappings);
+
+            input += &format!("\n{map} map:\n{}\n", mappings.join("\n"));
+        }
+
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 5));
+        assert_eq!(result, Ok(Some(35)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 5));
+        assert_eq!(result, Ok(Some(46)));
+    }
+
+    /// Map a single value the way the puzzle describes it.
+    fn naive_location(almanac: &Almanac, seed: i64) -> i64 {
+        [
+            &almanac.seed_to_soil,
+            &almanac.soil_to_fertilizer,
+            &almanac.fertilizer_to_water,
+            &almanac.water_to_light,
+            &almanac.light_to_temperature,
+            &almanac.temperature_to_humidity,
+            &almanac.humidity_to_location,
+        ]
+        .into_iter()
+        .fold(seed, |value, mappings| {
+            mappings
+                .iter()
+                .find(|m| (m.source..m.source + m.range).contains(&value))
+                .map_or(value, |m| value - m.source + m.destination)
+        })
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..20 {
+            let input = Day05::generate(&mut Rng::new(seed), 30).unwrap();
+            let almanac = Day05::parse(&input).unwrap();
+
+            let lowest = almanac
+                .seeds
+                .iter()
+                .map(|&s| naive_location(&almanac, s))
+                .min();
+            let part_one = Day05::part_one(&almanac).unwrap();
+            assert_eq!(part_one, lowest.map(|location| location as u64));
+
+            // the ranges of part two start at every other seed of part one
+            let starts = almanac.seeds.iter().step_by(2);
+            let lowest_start = starts.map(|&s| naive_location(&almanac, s)).min();
+            assert!(
+                Day05::part_two(&almanac).unwrap() <= lowest_start.map(|location| location as u64)
+            );
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::template::{
+    error::{parse_at, split_once_at, Error, Result},
+    Solution,
+};
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+/// The numbers after the `Time:` and `Distance:` labels.
+fn number_lines(input: &str) -> Result<(&str, &str)> {
+    let (time_line, distance_line) = split_once_at(input, input.trim_end(), "\n")?;
+    let (_, times) = split_once_at(input, time_line, ":")?;
+    let (_, distances) = split_once_at(input, distance_line, ":")?;
+    Ok((times, distances))
+}
+
+fn parse_races(input: &str) -> Result<Vec<Race>> {
+    let (times, distances) = number_lines(input)?;
+    let numbers = |line: &str| -> Result<Vec<u64>> {
+        line.split_whitespace()
+            .map(|number| parse_at(input, number))
+            .collect()
+    };
+    let (time_collection, distance_collection) = (numbers(times)?, numbers(distances)?);
+    if time_collection.len() != distance_collection.len() {
+        return Err(Error::at(
+            input,
+            distances,
+            format!(
+                "expected {} distances, got {}",
+                time_collection.len(),
+                distance_collection.len()
+            ),
+        ));
+    }
+
+    Ok(time_collection
+        .into_iter()
+        .zip(distance_collection)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+fn parse_race_bad_kerning(input: &str) -> Result<Race> {
+    let (times, distances) = number_lines(input)?;
+    let number = |line: &str| {
+        line.replace(" ", "").parse().map_err(|e| {
+            Error::at(
+                input,
+                line,
+                format!("invalid value \"{}\": {e}", line.trim()),
+            )
+        })
+    };
+
+    Ok(Race {
+        time: number(times)?,
+        distance: number(distances)?,
+    })
+}
+
+fn find_quad_roots(race: &Race) -> Result<Option<(f64, f64)>> {
+    let square = race
+        .time
+        .checked_pow(2)
+        .ok_or_else(|| Error::solver(format!("a race of {} ms is too long", race.time)))?;
+    let record = race
+        .distance
+        .checked_mul(4)
+        .ok_or_else(|| Error::solver(format!("a record of {} mm is too far", race.distance)))?;
+    // without two roots the boat never beats the record
+    let Some(trinomial) = square.checked_sub(record).filter(|&t| t > 0) else {
+        return Ok(None);
+    };
+    let trinomial = trinomial as f64;
+
+    let left = (-(race.time as f64) + trinomial.sqrt()) / -2.0;
+    let right = (-(race.time as f64) - trinomial.sqrt()) / -2.0;
+
+    Ok(Some((left.min(right), right.max(left))))
+}
+
+/// Number of ways to beat the record of `race`.
+fn ways(race: &Race) -> Result<u64> {
+    Ok(find_quad_roots(race)?.map_or(0, |(left, right)| {
+        ((right.ceil() - 1.0) - left).ceil().max(0.0) as u64
+    }))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day06::parse(input).and_then(|input| Day06::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>> {
+    Day06::parse(input).and_then(|input| Day06::part_two(&input))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let product = parse_races(input)?
+            .iter()
+            .try_fold(1_u64, |product, race| {
+                Ok(product.saturating_mul(ways(race)?))
+            })?;
+        u32::try_from(product)
+            .map(Some)
+            .map_err(|_| Error::solver("the product of the ways to win is too large"))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        ways(&parse_race_bad_kerning(input)?).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 6));
+        assert_eq!(result, Ok(Some(288)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 6));
+        assert_eq!(result, Ok(Some(71503)));
+    }
+}
@@ -1,3 +1,7 @@
+use crate::template::{
+    error::{parse_at, split_once_at, Error, Result},
+    Solution,
+};
 use fxhash::FxHashMap;
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
@@ -11,6 +15,40 @@ enum HandType {
     FiveOfAKind,
 }
 
+/// Parse `line`, a line of `input`, into its cards, their values with jacks worth `jack`, and its bid.
+fn parse_hand<'a>(input: &str, line: &'a str, jack: u8) -> Result<(&'a str, [u8; 5], u32)> {
+    let (raw_cards, bid) = split_once_at(input, line, " ")?;
+    let bid = parse_at(input, bid)?;
+    if raw_cards.len() != 5 {
+        return Err(Error::at(
+            input,
+            raw_cards,
+            format!("expected 5 cards, got {}", raw_cards.chars().count()),
+        ));
+    }
+
+    let mut cards = [0; 5];
+    for (i, card) in raw_cards.char_indices() {
+        cards[i] = match card {
+            'A' => 14,
+            'K' => 13,
+            'Q' => 12,
+            'J' => jack,
+            'T' => 10,
+            '2'..='9' => card as u8 - b'0',
+            _ => {
+                return Err(Error::at(
+                    input,
+                    &raw_cards[i..],
+                    format!("unknown card '{card}'"),
+                ))
+            }
+        };
+    }
+
+    Ok((raw_cards, cards, bid))
+}
+
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
 struct CamelHand {
     outcome: HandType,
@@ -19,23 +57,8 @@ struct CamelHand {
 }
 
 impl CamelHand {
-    fn new(input: &str) -> Self {
-        let (raw_cards, bid) = input.split_once(" ").expect("space delimited");
-        let bid: u32 = bid.parse().expect("Number value");
-
-        let mut cards = [0; 5];
-
-        for (i, &card) in raw_cards.as_bytes().iter().enumerate() {
-            match card {
-                b'A' => cards[i] = 14,
-                b'K' => cards[i] = 13,
-                b'Q' => cards[i] = 12,
-                b'J' => cards[i] = 11,
-                b'T' => cards[i] = 10,
-                digit @ b'0'..=b'9' => cards[i] = digit - b'0',
-                _ => (),
-            }
-        }
+    fn new(input: &str, line: &str) -> Result<Self> {
+        let (raw_cards, cards, bid) = parse_hand(input, line, 11)?;
 
         let mut card_count = FxHashMap::default();
         for card in raw_cards.chars() {
@@ -59,15 +82,15 @@ impl CamelHand {
                 }
             }
             4 => HandType::OnePair,
-            5 => HandType::HighCard,
-            _ => panic!("Impossible Hand sent: {:?}", raw_cards),
+            // five different cards, there are no more in a hand
+            _ => HandType::HighCard,
         };
 
-        Self {
+        Ok(Self {
             cards,
             outcome,
             bid,
-        }
+        })
     }
 }
 
@@ -79,23 +102,8 @@ struct JokerHand {
 }
 
 impl JokerHand {
-    fn new(input: &str) -> Self {
-        let (raw_cards, bid) = input.split_once(" ").expect("space delimited");
-        let bid: u32 = bid.parse().expect("Number value");
-
-        let mut cards = [0; 5];
-
-        for (i, &card) in raw_cards.as_bytes().iter().enumerate() {
-            match card {
-                b'A' => cards[i] = 14,
-                b'K' => cards[i] = 13,
-                b'Q' => cards[i] = 12,
-                b'J' => cards[i] = 0,
-                b'T' => cards[i] = 10,
-                digit @ b'0'..=b'9' => cards[i] = digit - b'0',
-                _ => (),
-            }
-        }
+    fn new(input: &str, line: &str) -> Result<Self> {
+        let (raw_cards, cards, bid) = parse_hand(input, line, 0)?;
 
         let mut card_count = FxHashMap::default();
         for card in raw_cards.chars() {
@@ -141,57 +149,73 @@ impl JokerHand {
                     HandType::OnePair
                 }
             }
-            5 => {
+            // five different cards, there are no more in a hand
+            _ => {
                 if card_count.contains_key(&'J') {
                     HandType::OnePair
                 } else {
                     HandType::HighCard
                 }
             }
-            _ => panic!("Impossible Hand sent: {:?}", raw_cards),
         };
 
-        Self {
+        Ok(Self {
             cards,
             outcome,
             bid,
-        }
+        })
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+/// Total winnings of the hands that `hand` parses from each line, ranked from weakest to strongest.
+fn winnings<H: Ord>(
+    input: &str,
+    hand: impl Fn(&str, &str) -> Result<H>,
+    bid: impl Fn(&H) -> u32,
+) -> Result<Option<u32>> {
     let mut cards = input
         .trim_end()
         .lines()
-        .map(CamelHand::new)
-        .collect::<Vec<_>>();
+        .map(|line| hand(input, line))
+        .collect::<Result<Vec<_>>>()?;
     cards.sort_unstable();
 
-    Some(
-        (1..)
-            .zip(cards.into_iter())
-            .map(|(rank, hand)| rank * hand.bid)
-            .sum(),
-    )
+    Ok(Some(
+        (1..).zip(&cards).map(|(rank, hand)| rank * bid(hand)).sum(),
+    ))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut cards = input
-        .trim_end()
-        .lines()
-        .map(JokerHand::new)
-        .collect::<Vec<_>>();
-    cards.sort_unstable();
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day07::parse(input).and_then(|input| Day07::part_one(&input))
+}
 
-    Some(
-        (1..)
-            .zip(cards.into_iter())
-            .map(|(rank, hand)| rank * hand.bid)
-            .sum(),
-    )
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day07::parse(input).and_then(|input| Day07::part_two(&input))
 }
 
-advent_of_code::main!(7);
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        winnings(input, CamelHand::new, |hand| hand.bid)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        winnings(input, JokerHand::new, |hand| hand.bid)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -199,13 +223,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", 7));
-        assert_eq!(result, Some(6440));
+        let result = part_one(&crate::template::read_file("examples", 2023, 7));
+        assert_eq!(result, Ok(Some(6440)));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", 7));
-        assert_eq!(result, Some(5905));
+        let result = part_two(&crate::template::read_file("examples", 2023, 7));
+        assert_eq!(result, Ok(Some(5905)));
     }
 }
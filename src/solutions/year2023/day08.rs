@@ -0,0 +1,142 @@
+use crate::template::{
+    error::{split_once_at, Error, Result},
+    Solution,
+};
+use crate::utils::number_theory::lcm_all;
+use fxhash::FxHashMap;
+use itertools::Itertools;
+use rayon::prelude::*;
+
+pub type Network<'a> = (Vec<char>, FxHashMap<&'a str, (&'a str, &'a str)>);
+
+fn parse(input: &str) -> Result<Network<'_>> {
+    let (instruction_string, direction_strings) = split_once_at(input, input.trim_end(), "\n\n")?;
+    if instruction_string.is_empty() {
+        return Err(Error::at(
+            input,
+            instruction_string,
+            "expected instructions",
+        ));
+    }
+    if let Some((i, c)) = instruction_string
+        .char_indices()
+        .find(|&(_, c)| c != 'L' && c != 'R')
+    {
+        return Err(Error::at(
+            input,
+            &instruction_string[i..],
+            format!("unknown instruction '{c}'"),
+        ));
+    }
+    let instructions = instruction_string.chars().collect_vec();
+    let mut routes = FxHashMap::default();
+
+    for line in direction_strings.lines() {
+        let (key, forks) = split_once_at(input, line, " = ")?;
+        let (left, right) =
+            split_once_at(input, forks.trim_matches(|c| c == '(' || c == ')'), ", ")?;
+        routes.insert(key, (left, right));
+    }
+
+    Ok((instructions, routes))
+}
+
+/// Number of steps from `start` until `end` holds for the current node.
+fn steps(network: &Network, start: &str, end: impl Fn(&str) -> bool) -> Result<u64> {
+    let (instructions, routes) = network;
+    // past this many steps some node was reached at the same instruction twice, so the walk goes round in circles
+    let limit = (routes.len() * instructions.len()) as u64;
+
+    let mut current = start;
+    let mut count: u64 = 0;
+
+    while !end(current) {
+        if count >= limit {
+            return Err(Error::solver(format!("no end is reachable from {start}")));
+        }
+        let index = count as usize % instructions.len();
+        let direction = instructions[index];
+
+        let route = routes
+            .get(current)
+            .ok_or_else(|| Error::solver(format!("there is no node {current}")))?;
+
+        current = if direction == 'L' { route.0 } else { route.1 };
+
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>> {
+    Day08::parse(input).and_then(|input| Day08::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>> {
+    Day08::parse(input).and_then(|input| Day08::part_two(&input))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input<'a> = Network<'a>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    /// `None` if there is no node `AAA`, like in the examples of part two.
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        if !input.1.contains_key("AAA") {
+            return Ok(None);
+        }
+        steps(input, "AAA", |node| node == "ZZZ").map(Some)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let starts: Vec<_> = input
+            .1
+            .keys()
+            .copied()
+            .filter(|&label| label.ends_with('A'))
+            .collect();
+
+        let cycles: Vec<u64> = starts
+            .par_iter()
+            .map(|&start| steps(input, start, |node| node.ends_with('Z')))
+            .collect::<Result<_>>()?;
+
+        lcm_all(cycles)
+            .map(Some)
+            .ok_or_else(|| Error::solver("number of steps overflows"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 8));
+        assert_eq!(result, Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 8));
+        assert_eq!(result, Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::template::examples::check::<Day08>();
+    }
+}
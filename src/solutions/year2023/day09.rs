@@ -0,0 +1,91 @@
+use crate::template::{
+    error::{parse_at, Result},
+    Solution,
+};
+use itertools::Itertools;
+
+/// The next value of `reading`, from the sum of the last values of its differences.
+fn extrapolate(reading: impl Iterator<Item = i64>) -> i64 {
+    let mut prediction = 0;
+    let mut sub_level: Vec<i64> = reading.collect();
+    while let Some(&last) = sub_level.last() {
+        if sub_level.iter().all(|&value| value == 0) {
+            break;
+        }
+        prediction += last;
+        sub_level = sub_level
+            .into_iter()
+            .tuple_windows()
+            .map(|(a, b)| b - a)
+            .collect();
+    }
+    prediction
+}
+
+pub fn part_one(input: &str) -> Result<Option<i64>> {
+    Day09::parse(input).and_then(|readings| Day09::part_one(&readings))
+}
+
+pub fn part_two(input: &str) -> Result<Option<i64>> {
+    Day09::parse(input).and_then(|readings| Day09::part_two(&readings))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input<'a> = Vec<Vec<i64>>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .trim_end()
+            .lines()
+            .map(|line| {
+                line.split_ascii_whitespace()
+                    .map(|num| parse_at(input, num))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_one(readings: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(
+            readings
+                .iter()
+                .map(|reading| extrapolate(reading.iter().copied()))
+                .sum(),
+        ))
+    }
+
+    fn part_two(readings: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        // what is the front but the reversed last
+        Ok(Some(
+            readings
+                .iter()
+                .map(|reading| extrapolate(reading.iter().rev().copied()))
+                .sum(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 9));
+        assert_eq!(result, Ok(Some(114)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 9));
+        assert_eq!(result, Ok(Some(2)));
+    }
+}
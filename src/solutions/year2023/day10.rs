@@ -0,0 +1,269 @@
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::{
+    visualize::{Canvas, Color},
+    Grid, Point,
+};
+use itertools::Itertools;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+fn get(grid: &Grid<u8>, x: i32, y: i32) -> u8 {
+    grid[(x as usize, y as usize)]
+}
+
+fn get_adjacent(grid: &Grid<u8>, x: i32, y: i32) -> impl Iterator<Item = (u8, i32, i32)> + '_ {
+    [(1, 0), (-1, 0), (0, 1), (0, -1)]
+        .into_iter()
+        .filter(move |(delta_x, delta_y)| {
+            grid.contains((x + delta_x) as isize, (y + delta_y) as isize)
+        })
+        .map(move |(delta_x, delta_y)| (get(grid, x + delta_x, y + delta_y), delta_x, delta_y))
+}
+
+/// Tiles of the loop through the start, in the order they are walked.
+fn trace_loop(Maze { grid, start }: &Maze) -> Vec<(i32, i32)> {
+    let mut visited = HashSet::new();
+    let mut perimiter = vec![*start];
+    visited.insert(*start);
+
+    let mut location_stack = vec![*start];
+
+    while let Some(current_location) = location_stack.pop() {
+        let (x, y) = current_location;
+        let current_symbol = get(grid, x, y);
+        for (adjacent, delta_x, delta_y) in get_adjacent(grid, x, y) {
+            match (adjacent, current_symbol, delta_x, delta_y) {
+                (b'|', b'7' | b'F' | b'|', 0, 1) |
+                (b'|', b'L' | b'J' | b'|' | b'S', 0, -1) |  // make work for more than my input later
+                (b'-', b'F' | b'L' | b'-', 1, 0) |
+                (b'-', b'7' | b'J' | b'-', -1, 0) |
+                (b'L', b'7' | b'J' | b'-', -1, 0) |
+                (b'L', b'F' | b'7' | b'|', 0, 1) |
+                (b'J', b'L' | b'F' | b'-', 1, 0) |
+                (b'J', b'7' | b'F' | b'|', 0, 1) |
+                (b'7', b'L' | b'F' | b'-', 1, 0) |
+                (b'7', b'L' | b'J' | b'|', 0, -1) |
+                (b'F', b'J' | b'7' | b'-', -1, 0) |
+                (b'F', b'L' | b'J' | b'|', 0, -1) => {
+                    let new_x = x + delta_x;
+                    let new_y = y + delta_y;
+
+                    if visited.insert((new_x, new_y)) {
+                        location_stack.push((new_x, new_y));
+                        perimiter.push((new_x, new_y));
+                    }
+                }
+                _ => ()
+            }
+        }
+    }
+
+    perimiter
+}
+
+pub fn part_one(input: &str) -> Result<Option<i32>> {
+    Day10::parse(input).and_then(|input| Day10::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<i32>> {
+    Day10::parse(input).and_then(|input| Day10::part_two(&input))
+}
+
+pub struct Maze {
+    grid: Grid<u8>,
+    start: (i32, i32),
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input<'a> = Maze;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let grid = Grid::parse(input, |c| c.is_ascii().then_some(c as u8))?;
+        let (x, y) = grid
+            .position(|&tile| tile == b'S')
+            .ok_or_else(|| Error::solver("there is no starting tile 'S'"))?;
+
+        Ok(Maze {
+            grid,
+            start: (x as i32, y as i32),
+        })
+    }
+
+    fn part_one(Maze { grid, start }: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let mut distances = HashMap::new();
+        distances.insert(*start, 0);
+
+        let mut location_stack = vec![*start];
+
+        while let Some(current_location) = location_stack.pop() {
+            let distance = distances[&current_location];
+            let (x, y) = current_location;
+            let current_symbol = get(grid, x, y);
+            for (adjacent, delta_x, delta_y) in get_adjacent(grid, x, y) {
+                match (adjacent, current_symbol, delta_x, delta_y) {
+                    (b'|', b'7' | b'F' | b'|' | b'S', 0, 1)
+                    | (b'|', b'L' | b'J' | b'|' | b'S', 0, -1)
+                    | (b'-', b'F' | b'L' | b'-' | b'S', 1, 0)
+                    | (b'-', b'7' | b'J' | b'-' | b'S', -1, 0)
+                    | (b'L', b'7' | b'J' | b'-' | b'S', -1, 0)
+                    | (b'L', b'F' | b'7' | b'|' | b'S', 0, 1)
+                    | (b'J', b'L' | b'F' | b'-' | b'S', 1, 0)
+                    | (b'J', b'7' | b'F' | b'|' | b'S', 0, 1)
+                    | (b'7', b'L' | b'F' | b'-' | b'S', 1, 0)
+                    | (b'7', b'L' | b'J' | b'|' | b'S', 0, -1)
+                    | (b'F', b'J' | b'7' | b'-' | b'S', -1, 0)
+                    | (b'F', b'L' | b'J' | b'|' | b'S', 0, -1) => {
+                        let new_x = x + delta_x;
+                        let new_y = y + delta_y;
+                        let step = distance + 1;
+                        match distances.entry((new_x, new_y)) {
+                            Entry::Vacant(item) => {
+                                item.insert(step);
+                                location_stack.push((new_x, new_y));
+                            }
+                            Entry::Occupied(mut item) => {
+                                if step < *item.get_mut() {
+                                    item.insert(step);
+                                    location_stack.push((new_x, new_y));
+                                }
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(distances.values().max().copied())
+    }
+
+    fn part_two(maze: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let mut perimiter = trace_loop(maze);
+
+        let num_points = perimiter.len() as i32;
+
+        //seal up perimeter of points
+        perimiter.push(maze.start);
+
+        let mut reg_poly_area = 0;
+
+        for ((x_1, y_1), (x_2, y_2)) in perimiter.into_iter().tuple_windows() {
+            reg_poly_area += (x_1 - x_2) * (y_2 + y_1);
+        }
+
+        Ok(Some(reg_poly_area.abs() / 2 - (num_points / 2 - 1)))
+    }
+
+    /// Pipes as box-drawing characters and the loop through the start. Part one marks the tile farthest from the
+    /// start, part two the tiles enclosed by the loop.
+    fn visualize(maze: &Self::Input<'_>, part: u8) -> Result<Option<Canvas>> {
+        let grid = &maze.grid;
+        let mut canvas = Canvas::from_grid(grid, |&tile| match tile {
+            b'|' => '│',
+            b'-' => '─',
+            b'L' => '└',
+            b'J' => '┘',
+            b'7' => '┐',
+            b'F' => '┌',
+            tile => tile as char,
+        });
+
+        let perimiter = trace_loop(maze);
+        let point = |&(x, y): &(i32, i32)| Point::new(x.into(), y.into());
+        canvas.polygon(perimiter.iter().map(point), Color::YELLOW);
+
+        if part == 1 {
+            canvas.highlight(perimiter.get(perimiter.len() / 2).map(point), Color::RED);
+        } else {
+            // a tile is enclosed if a ray to its left crosses the loop an odd number of times, counting the pipes
+            // that connect to the north
+            let on_loop: HashSet<_> = perimiter.iter().copied().collect();
+            let start = perimiter[0];
+            let start_goes_north =
+                [perimiter.get(1), perimiter.last()].contains(&Some(&(start.0, start.1 - 1)));
+
+            let mut enclosed = Vec::new();
+            for y in 0..grid.height() as i32 {
+                let mut inside = false;
+                for x in 0..grid.width() as i32 {
+                    if on_loop.contains(&(x, y)) {
+                        match get(grid, x, y) {
+                            b'|' | b'L' | b'J' => inside = !inside,
+                            b'S' if start_goes_north => inside = !inside,
+                            _ => (),
+                        }
+                    } else if inside {
+                        enclosed.push((x, y));
+                    }
+                }
+            }
+            canvas.highlight(enclosed.iter().map(point), Color::BLUE);
+        }
+
+        Ok(Some(canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 10));
+        assert_eq!(result, Ok(Some(80)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 10));
+        assert_eq!(result, Ok(Some(10)));
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::template::examples::check::<Day10>();
+    }
+
+    #[test]
+    fn test_visualize() {
+        let input = [
+            "..........",
+            ".F------7.",
+            ".|F----7|.",
+            ".||....||.",
+            ".S|....||.",
+            ".|L-7F-J|.",
+            ".|..||..|.",
+            ".L--JL--J.",
+            "..........",
+        ]
+        .join("\n");
+        let maze = Day10::parse(&input).unwrap();
+        assert_eq!(Day10::part_two(&maze), Ok(Some(4)));
+
+        let canvas = Day10::visualize(&maze, 2).unwrap().unwrap();
+        let ansi = canvas.to_ansi();
+        // the four enclosed tiles are blue, the loop is yellow
+        assert_eq!(ansi.matches("\x1b[48;2;64;128;224m").count(), 4);
+        assert_eq!(ansi.matches("\x1b[48;2;240;208;64m").count(), 44);
+    }
+
+    #[test]
+    fn test_missing_start() {
+        let error = Err(Error::solver("there is no starting tile 'S'"));
+        assert_eq!(part_one(""), error);
+        assert_eq!(part_one(".F7.\n.LJ.\n"), error);
+    }
+}
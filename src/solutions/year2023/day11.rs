@@ -0,0 +1,129 @@
+use crate::template::{
+    error::{Error, Result},
+    Parameters, Solution,
+};
+use crate::utils::{Grid, Point};
+
+fn galaxy_distance_sums(image: &Grid<bool>, expansion_factor: usize) -> usize {
+    // how many empty columns and rows there are up to and including each index
+    let empty = |lines: Vec<bool>| -> Vec<usize> {
+        lines
+            .into_iter()
+            .scan(0, |sum, empty| {
+                *sum += usize::from(empty);
+                Some(*sum)
+            })
+            .collect()
+    };
+    let x_sum = empty(
+        image
+            .columns()
+            .map(|mut column| !column.any(|&galaxy| galaxy))
+            .collect(),
+    );
+    let y_sum = empty(image.rows().map(|row| !row.contains(&true)).collect());
+
+    let expand = |(x, y): (usize, usize)| {
+        Point::from((
+            x + (x_sum[x] * (expansion_factor - 1)),
+            y + (y_sum[y] * (expansion_factor - 1)),
+        ))
+    };
+
+    let galaxies: Vec<_> = image
+        .iter()
+        .filter(|&(_, &galaxy)| galaxy)
+        .map(|(position, _)| expand(position))
+        .collect();
+
+    let mut sum = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            sum += galaxies[i].manhattan(galaxies[j]) as usize;
+        }
+    }
+
+    sum
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>> {
+    Day11::parse(input).and_then(|universe| Day11::part_one(&universe))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>> {
+    Day11::parse(input).and_then(|universe| Day11::part_two(&universe))
+}
+
+pub struct Universe {
+    image: Grid<bool>,
+    /// How many times larger empty rows and columns are in part two.
+    expansion: usize,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    type Input<'a> = Universe;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Input<'a>> {
+        let expansion = parameters.get_or("expansion", 1000000)?;
+        if expansion == 0 {
+            return Err(Error::solver("the expansion factor has to be at least 1"));
+        }
+
+        Ok(Universe {
+            image: Grid::parse(input, |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })?,
+            expansion,
+        })
+    }
+
+    fn part_one(universe: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(galaxy_distance_sums(&universe.image, 2)))
+    }
+
+    fn part_two(universe: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Ok(Some(galaxy_distance_sums(
+            &universe.image,
+            universe.expansion,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 11));
+        assert_eq!(result, Ok(Some(374)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 11));
+        assert_eq!(result, Ok(Some(82000210)));
+    }
+
+    #[test]
+    fn test_part_two_expansion() {
+        let input = crate::template::read_file("examples", 2023, 11);
+        let universe = Day11::parse_with(&input, &Parameters::new().with("expansion", 10));
+        let result = universe.and_then(|universe| Day11::part_two(&universe));
+        assert_eq!(result, Ok(Some(1030)));
+    }
+}
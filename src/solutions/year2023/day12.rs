@@ -0,0 +1,276 @@
+use crate::template::{
+    error::{parse_at, split_once_at, Error, Result},
+    Solution,
+};
+use crate::utils::Rng;
+use itertools::Itertools;
+use rayon::prelude::*;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Record {
+    springs: Vec<Spring>,
+    counts: Vec<u32>,
+}
+
+/// Springs and counts of damaged groups in `line`, a line of `input`.
+fn parse_line(input: &str, line: &str) -> Result<(Vec<Spring>, Vec<u32>)> {
+    let (springs, counts) = split_once_at(input, line, " ")?;
+    let springs = springs
+        .char_indices()
+        .map(|(i, c)| match c {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => Err(Error::at(
+                input,
+                &springs[i..],
+                format!("invalid spring '{c}'"),
+            )),
+        })
+        .collect::<Result<_>>()?;
+    let counts = counts
+        .split(",")
+        .map(|c| parse_at(input, c))
+        .collect::<Result<_>>()?;
+
+    Ok((springs, counts))
+}
+
+impl Record {
+    fn new(input: &str, line: &str) -> Result<Self> {
+        let (mut springs, counts) = parse_line(input, line)?;
+
+        // simplify Damaged recursion case
+        springs.push(Spring::Operational);
+
+        Ok(Self { springs, counts })
+    }
+
+    fn newewewewew(input: &str, line: &str) -> Result<Self> {
+        let (mut springs, mut counts) = parse_line(input, line)?;
+
+        // five times as much apparently
+        springs = springs
+            .iter()
+            .copied()
+            .chain([Spring::Unknown])
+            .cycle()
+            .take(springs.len() * 5 + 4)
+            .collect();
+
+        // simplify Damaged recursion case
+        springs.push(Spring::Operational);
+
+        counts = counts
+            .iter()
+            .copied()
+            .cycle()
+            .take(counts.len() * 5)
+            .collect();
+
+        Ok(Self { springs, counts })
+    }
+
+    fn _is_valid(&self) -> bool {
+        self.springs
+            .iter()
+            .group_by(|&item| item)
+            .into_iter()
+            .filter_map(|(key, group)| {
+                if *key == Spring::Damaged {
+                    Some(group.count() as u32)
+                } else {
+                    None
+                }
+            })
+            .eq(self.counts.iter().copied())
+    }
+
+    fn find_valid_configurations(&self) -> u64 {
+        let mut cache = vec![vec![None; self.springs.len()]; self.counts.len()];
+        count_possible_arrangements(&self.springs, &self.counts, &mut cache)
+    }
+}
+
+fn count_possible_arrangements(
+    springs: &[Spring],
+    counts: &[u32],
+    cache: &mut [Vec<Option<u64>>],
+) -> u64 {
+    // quick cases out of the way first
+    if counts.is_empty() {
+        return if springs.contains(&Spring::Damaged) {
+            // rest of springs are damaged out of count
+            0
+        } else {
+            // remaining springs are good
+            1
+        };
+    }
+    if springs.len() < (counts.iter().map(|&count| count as usize).sum::<usize>() + counts.len()) {
+        // not enough space for remaining counts
+        return 0;
+    }
+
+    if let Some(cached) = cache[counts.len() - 1][springs.len() - 1] {
+        return cached;
+    }
+
+    let mut arrangements = 0;
+    if springs[0] != Spring::Damaged {
+        // assume operational
+        arrangements += count_possible_arrangements(&springs[1..], counts, cache);
+    }
+
+    let group_size = counts[0] as usize;
+
+    if !springs[..group_size].contains(&Spring::Operational)
+        && springs[group_size] != Spring::Damaged
+    {
+        // assume damaged
+        arrangements +=
+            count_possible_arrangements(&springs[group_size + 1..], &counts[1..], cache);
+    }
+    cache[counts.len() - 1][springs.len() - 1] = Some(arrangements);
+    arrangements
+}
+
+/// Sum of the arrangements of the records that `record` parses from each line.
+fn arrangements(input: &str, record: impl Fn(&str, &str) -> Result<Record>) -> Result<Option<u64>> {
+    let records = input
+        .trim_end()
+        .lines()
+        .map(|line| record(input, line))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(
+        records
+            .par_iter()
+            .map(|record| record.find_valid_configurations())
+            .sum(),
+    ))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>> {
+    Day12::parse(input).and_then(|input| Day12::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>> {
+    Day12::parse(input).and_then(|input| Day12::part_two(&input))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    type Input<'a> = &'a str;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        arrangements(input, Record::new)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        arrangements(input, Record::newewewewew)
+    }
+
+    /// `size` records of up to 20 springs. Each one is generated from a known arrangement, so it has at least one.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let records: Vec<_> = (0..size)
+            .map(|_| {
+                let length = rng.range(1..21) as usize;
+                let mut springs: Vec<_> = (0..length).map(|_| rng.chance(0.4)).collect();
+                if !springs.contains(&true) {
+                    // records always have at least one group of damaged springs
+                    springs[rng.below(length as u64) as usize] = true;
+                }
+
+                let counts = springs
+                    .split(|&damaged| !damaged)
+                    .filter(|group| !group.is_empty())
+                    .map(|group| group.len().to_string())
+                    .join(",");
+                let pattern: String = springs
+                    .iter()
+                    .map(|&damaged| match (rng.chance(0.5), damaged) {
+                        (true, _) => '?',
+                        (false, true) => '#',
+                        (false, false) => '.',
+                    })
+                    .collect();
+
+                format!("{pattern} {counts}")
+            })
+            .collect();
+
+        Some(records.join("\n") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 12));
+        assert_eq!(result, Ok(Some(21)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 12));
+        assert_eq!(result, Ok(Some(525152)));
+    }
+
+    /// Count arrangements by trying every combination of unknown springs.
+    fn brute_force(line: &str) -> u64 {
+        let record = Record::new(line, line).unwrap();
+        let unknowns = record
+            .springs
+            .iter()
+            .positions(|&spring| spring == Spring::Unknown)
+            .collect_vec();
+
+        (0..1u32 << unknowns.len())
+            .filter(|combination| {
+                let mut candidate = record.clone();
+                for (bit, &i) in unknowns.iter().enumerate() {
+                    candidate.springs[i] = if combination & (1 << bit) == 0 {
+                        Spring::Operational
+                    } else {
+                        Spring::Damaged
+                    };
+                }
+                candidate._is_valid()
+            })
+            .count() as u64
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..20 {
+            let input = Day12::generate(&mut Rng::new(seed), 20).unwrap();
+            for line in input.lines() {
+                let arrangements = part_one(line).unwrap();
+                assert!(arrangements >= Some(1), "{line}");
+                assert_eq!(arrangements, Some(brute_force(line)), "{line}");
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::{Grid, Rng};
+
+/// Parse the patterns separated by blank lines, `#` for rocks and `.` for ash.
+fn patterns<T>(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Vec<Grid<T>>> {
+    input
+        .trim_end()
+        .split("\n\n")
+        .map(|pattern| {
+            let rows = pattern
+                .lines()
+                .map(|line| {
+                    line.char_indices()
+                        .map(|(i, c)| {
+                            cell(c).ok_or_else(|| {
+                                Error::at(input, &line[i..], format!("unexpected character '{c}'"))
+                            })
+                        })
+                        .collect()
+                })
+                .collect::<Result<_>>()?;
+            Grid::from_rows(rows)
+                .ok_or_else(|| Error::at(input, pattern, "rows of the pattern differ in length"))
+        })
+        .collect()
+}
+
+fn rock(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+/// A row or column as bits, rocks set.
+fn bits<'a>(cells: impl Iterator<Item = &'a bool>) -> u32 {
+    cells.fold(0, |bits, &rock| bits << 1 | u32::from(rock))
+}
+
+/// The first line with `smudges` differing cells across it.
+fn reflection(lines: &[u32], smudges: u32) -> Option<usize> {
+    (1..lines.len()).find(|&line| {
+        lines[..line]
+            .iter()
+            .rev()
+            .zip(&lines[line..])
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum::<u32>()
+            == smudges
+    })
+}
+
+/// Columns left of a vertical line of reflection, or 100 times the rows above a horizontal one.
+fn summarize(grid: &Grid<bool>, smudges: u32) -> Option<u32> {
+    let columns: Vec<_> = grid.columns().map(bits).collect();
+    let rows: Vec<_> = grid.rows().map(|row| bits(row.iter())).collect();
+
+    reflection(&columns, smudges)
+        .map(|column| column as u32)
+        .or_else(|| reflection(&rows, smudges).map(|row| 100 * row as u32))
+}
+
+fn sum(patterns: &[Grid<bool>], smudges: u32) -> Result<Option<u32>> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(i, grid)| {
+            summarize(grid, smudges).ok_or_else(|| {
+                Error::solver(format!("pattern {} has no line of reflection", i + 1))
+            })
+        })
+        .sum::<Result<u32>>()
+        .map(Some)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day13::parse(input).and_then(|input| Day13::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day13::parse(input).and_then(|input| Day13::part_two(&input))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    type Input<'a> = Vec<Grid<bool>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let patterns = patterns(input, rock)?;
+        // rows and columns are compared as bits of a `u32`
+        if let Some(i) = patterns
+            .iter()
+            .position(|grid| grid.width() > 32 || grid.height() > 32)
+        {
+            return Err(Error::solver(format!(
+                "pattern {} is larger than 32 by 32",
+                i + 1
+            )));
+        }
+        Ok(patterns)
+    }
+
+    fn part_one(patterns: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        sum(patterns, 0)
+    }
+
+    fn part_two(patterns: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        sum(patterns, 1)
+    }
+
+    /// `size` patterns with a horizontal line of reflection, and a vertical one that is off by a single smudge.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let patterns: Vec<String> = (0..size)
+            .map(|_| {
+                // at most 17 wide and high, rows and columns are compared as bits of a `u32`
+                let width = rng.range(2..18) as usize;
+                let height = rng.range(3..18) as usize;
+                let column = rng.range(1..width as i64) as usize;
+                // off the middle, so that some rows are not reflected and can take the smudge
+                let row = loop {
+                    let row = rng.range(1..height as i64) as usize;
+                    if 2 * row != height {
+                        break row;
+                    }
+                };
+
+                let mut grid = Grid::filled(width, height, false);
+                for y in 0..height {
+                    for x in 0..width {
+                        grid[(x, y)] = rng.chance(0.5);
+                    }
+                    for x in 0..column.min(width - column) {
+                        grid[(column + x, y)] = grid[(column - 1 - x, y)];
+                    }
+                }
+                for y in 0..row.min(height - row) {
+                    for x in 0..width {
+                        grid[(x, row + y)] = grid[(x, row - 1 - y)];
+                    }
+                }
+
+                let unreflected = if 2 * row < height {
+                    2 * row..height
+                } else {
+                    0..2 * row - height
+                };
+                let y = unreflected.start + rng.below(unreflected.len() as u64) as usize;
+                let x = column + rng.below(column.min(width - column) as u64) as usize;
+                grid[(x, y)] = !grid[(x, y)];
+
+                grid.map(|&rock| if rock { '#' } else { '.' }).to_string()
+            })
+            .collect();
+
+        // every pattern ends in a newline already
+        Some(patterns.join("\n"))
+    }
+}
+
+/// Compares rows and columns as strings instead of bits, which the fast version was checked against.
+#[cfg(test)]
+pub struct Day13Reference;
+
+#[cfg(test)]
+impl Day13Reference {
+    fn diff_strings(str0: &str, str1: &str) -> u32 {
+        str0.chars()
+            .zip(str1.chars())
+            .filter(|&(c0, c1)| c0 != c1)
+            .count() as u32
+    }
+
+    /// Summary of the first line, columns before rows, across which exactly `smudges` cells differ.
+    fn summarize(grid: &Grid<char>, smudges: u32) -> Option<u32> {
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        let find = |lines: &[String]| {
+            (1..lines.len()).find(|&line| {
+                let before = lines[..line].iter().rev();
+                let after = lines[line..].iter();
+                before
+                    .zip(after)
+                    .map(|(a, b)| Self::diff_strings(a, b))
+                    .sum::<u32>()
+                    == smudges
+            })
+        };
+
+        find(&columns)
+            .map(|column| column as u32)
+            .or_else(|| find(&rows).map(|row| 100 * row as u32))
+    }
+
+    fn sum(patterns: &[Grid<char>], smudges: u32) -> Result<Option<u32>> {
+        patterns
+            .iter()
+            .map(|grid| {
+                Self::summarize(grid, smudges)
+                    .ok_or_else(|| Error::solver("pattern has no line of reflection"))
+            })
+            .sum::<Result<u32>>()
+            .map(Some)
+    }
+}
+
+#[cfg(test)]
+impl Solution for Day13Reference {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    type Input<'a> = Vec<Grid<char>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        patterns(input, |c| matches!(c, '#' | '.').then_some(c))
+    }
+
+    fn part_one(patterns: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Self::sum(patterns, 0)
+    }
+
+    fn part_two(patterns: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Self::sum(patterns, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 13));
+        assert_eq!(result, Ok(Some(405)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 13));
+        assert_eq!(result, Ok(Some(400)));
+    }
+
+    #[test]
+    fn test_reference() {
+        crate::template::differential::check::<Day13, Day13Reference>(50, 30);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::template::{error::Result, Solution};
+use crate::utils::{
+    cycle, trace,
+    visualize::{Canvas, Color},
+    Grid, Point, Rng,
+};
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Space {
+    Rounded,
+    Cubed,
+    Empty,
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Platform {
+    grid: Grid<Space>,
+}
+
+impl Platform {
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse(input, |char| match char {
+            '#' => Some(Space::Cubed),
+            'O' => Some(Space::Rounded),
+            '.' => Some(Space::Empty),
+            _ => None,
+        })?;
+
+        Ok(Platform { grid })
+    }
+
+    fn slide_north(&mut self) {
+        for x in 0..self.grid.width() {
+            let mut fixed = 0;
+            for y in 0..self.grid.height() {
+                match self.grid[(x, y)] {
+                    Space::Rounded => {
+                        if y > fixed {
+                            self.grid[(x, y)] = Space::Empty;
+                            self.grid[(x, fixed)] = Space::Rounded;
+                        }
+                        fixed += 1;
+                    }
+                    Space::Cubed => fixed = y + 1,
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    fn slide_west(&mut self) {
+        for y in 0..self.grid.height() {
+            let mut fixed = 0;
+            for x in 0..self.grid.width() {
+                match self.grid[(x, y)] {
+                    Space::Rounded => {
+                        if x > fixed {
+                            self.grid[(x, y)] = Space::Empty;
+                            self.grid[(fixed, y)] = Space::Rounded;
+                        }
+                        fixed += 1;
+                    }
+                    Space::Cubed => fixed = x + 1,
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    fn slide_south(&mut self) {
+        for x in 0..self.grid.width() {
+            let mut fixed: i32 = self.grid.height() as i32 - 1;
+            for y in (0..self.grid.height()).rev() {
+                match self.grid[(x, y)] {
+                    Space::Rounded => {
+                        if (y as i32) < fixed {
+                            self.grid[(x, y)] = Space::Empty;
+                            self.grid[(x, fixed as usize)] = Space::Rounded;
+                        }
+                        fixed -= 1;
+                    }
+                    Space::Cubed => fixed = y as i32 - 1,
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    fn slide_east(&mut self) {
+        for y in 0..self.grid.height() {
+            let mut fixed: i32 = self.grid.width() as i32 - 1;
+            for x in (0..self.grid.width()).rev() {
+                match self.grid[(x, y)] {
+                    Space::Rounded => {
+                        if (x as i32) < fixed {
+                            self.grid[(x, y)] = Space::Empty;
+                            self.grid[(fixed as usize, y)] = Space::Rounded;
+                        }
+                        fixed -= 1;
+                    }
+                    Space::Cubed => fixed = x as i32 - 1,
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    fn spin(&mut self) {
+        self.slide_north();
+        trace::frame(|| self.canvas());
+        self.slide_west();
+        trace::frame(|| self.canvas());
+        self.slide_south();
+        trace::frame(|| self.canvas());
+        self.slide_east();
+        trace::frame(|| self.canvas());
+    }
+
+    fn load(&self) -> u32 {
+        self.grid
+            .iter()
+            .filter(|(_, &space)| space == Space::Rounded)
+            .map(|((_, y), _)| (self.grid.height() - y) as u32)
+            .sum()
+    }
+
+    /// The platform with its rounded rocks in yellow.
+    fn canvas(&self) -> Canvas {
+        let mut canvas = Canvas::from_grid(&self.grid, |space| match space {
+            Space::Rounded => 'O',
+            Space::Cubed => '#',
+            Space::Empty => '.',
+        });
+        let rounded = self
+            .grid
+            .iter()
+            .filter(|(_, &space)| space == Space::Rounded)
+            .map(|((x, y), _)| Point::new(x as i64, y as i64));
+        canvas.highlight(rounded, Color::YELLOW);
+        canvas
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day14::parse(input).and_then(|platform| Day14::part_one(&platform))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day14::parse(input).and_then(|platform| Day14::part_two(&platform))
+}
+
+fn tilt_north(platform: &Platform) -> u32 {
+    let mut platform = platform.clone();
+
+    trace::frame(|| platform.canvas());
+    platform.slide_north();
+    trace::frame(|| platform.canvas());
+    platform.load()
+}
+
+fn spin_cycle(platform: &Platform) -> u32 {
+    // lmao for teh lols
+    // for _ in 0..1000000000 {
+    //     platform.spin();
+    // }
+
+    let platform = cycle::state_after(
+        platform.clone(),
+        |platform| {
+            let mut platform = platform.clone();
+            platform.spin();
+            platform
+        },
+        1_000_000_000,
+    );
+
+    platform.load()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    type Input<'a> = Platform;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Platform::parse(input)
+    }
+
+    fn part_one(platform: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(tilt_north(platform)))
+    }
+
+    fn part_two(platform: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Ok(Some(spin_cycle(platform)))
+    }
+
+    /// A square platform with sides of `size`, a quarter rounded rocks and a sixth cube-shaped ones.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let rows: Vec<String> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.below(12) {
+                        0..=2 => 'O',
+                        3..=4 => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(rows.join("\n") + "\n")
+    }
+}
+
+/// Rolls rocks one cell at a time until none moves and spots the cycle by comparing against every earlier state,
+/// which the sliding and hashing of the fast version were checked against.
+#[cfg(test)]
+pub struct Day14Reference;
+
+#[cfg(test)]
+impl Day14Reference {
+    /// Tilt the platform so that rocks roll towards `(dx, dy)`.
+    fn tilt(grid: &mut [Vec<char>], (dx, dy): (isize, isize)) {
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for y in 0..grid.len() {
+                for x in 0..grid[y].len() {
+                    let (Some(to_x), Some(to_y)) =
+                        (x.checked_add_signed(dx), y.checked_add_signed(dy))
+                    else {
+                        continue;
+                    };
+                    if grid[y][x] == 'O'
+                        && grid.get(to_y).and_then(|row| row.get(to_x)) == Some(&'.')
+                    {
+                        grid[y][x] = '.';
+                        grid[to_y][to_x] = 'O';
+                        moved = true;
+                    }
+                }
+            }
+        }
+    }
+
+    fn load(grid: &[Vec<char>]) -> u32 {
+        grid.iter()
+            .enumerate()
+            .map(|(y, row)| {
+                (grid.len() - y) as u32 * row.iter().filter(|&&c| c == 'O').count() as u32
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+impl Solution for Day14Reference {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    type Input<'a> = Vec<Vec<char>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let mut grid = grid.clone();
+        Self::tilt(&mut grid, (0, -1));
+        Ok(Some(Self::load(&grid)))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        const SPINS: usize = 1_000_000_000;
+
+        let mut history = vec![grid.clone()];
+        loop {
+            let mut grid = history[history.len() - 1].clone();
+            for direction in [(0, -1), (-1, 0), (0, 1), (1, 0)] {
+                Self::tilt(&mut grid, direction);
+            }
+
+            if let Some(start) = history.iter().position(|earlier| *earlier == grid) {
+                let length = history.len() - start;
+                return Ok(Some(Self::load(&history[start + (SPINS - start) % length])));
+            }
+            history.push(grid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 14));
+        assert_eq!(result, Ok(Some(136)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 14));
+        assert_eq!(result, Ok(Some(64)));
+    }
+
+    #[test]
+    fn test_reference() {
+        crate::template::differential::check::<Day14, Day14Reference>(30, 12);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::template::{
+    error::{parse_at, Error, Result},
+    Solution,
+};
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Box<'a> {
+    label: &'a str,
+    lens: u32,
+}
+
+fn hash(input: &[u8]) -> u32 {
+    input.iter().fold(0_u16, |mut val, x| {
+        val += *x as u16;
+        val *= 17;
+        val %= 256;
+        val
+    }) as u32
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day15::parse(input).and_then(|input| Day15::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>> {
+    Day15::parse(input).and_then(|input| Day15::part_two(&input))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(
+            input
+                .trim_end()
+                .split(",")
+                .map(str::as_bytes)
+                .map(hash)
+                .sum(),
+        ))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let mut boxes: Vec<Vec<Box>> = vec![Vec::new(); 256];
+
+        for entry in input.trim_end().split(",") {
+            let character_index = entry
+                .find(['=', '-'])
+                .ok_or_else(|| Error::at(input, entry, "expected '=' or '-' after the label"))?;
+            let label = &entry[..character_index];
+            let num = &entry[character_index + 1..];
+
+            let items = &mut boxes[hash(label.as_bytes()) as usize];
+
+            if entry[character_index..].starts_with('=') {
+                let new_box = Box {
+                    label,
+                    lens: parse_at(input, num)?,
+                };
+
+                match items.iter_mut().find(|item| item.label == new_box.label) {
+                    Some(item) => item.lens = new_box.lens,
+                    None => items.push(new_box),
+                }
+            } else if let Some(to_remove) = items.iter().rposition(|item| item.label == label) {
+                items.remove(to_remove);
+            }
+        }
+
+        Ok(Some(
+            boxes
+                .iter()
+                .enumerate()
+                .map(|(box_index, boxes)| {
+                    let mut total = 0;
+                    for (i, x) in boxes.iter().enumerate() {
+                        total += (box_index + 1) as u64 * (i + 1) as u64 * x.lens as u64;
+                    }
+                    total
+                })
+                .sum(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 15));
+        assert_eq!(result, Ok(Some(1320)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 15));
+        assert_eq!(result, Ok(Some(145)));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::template::{error::Result, Solution};
+use crate::utils::{
+    visualize::{Canvas, Color},
+    Direction, Grid, Point,
+};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+struct Laser {
+    position: Point,
+    direction: Direction,
+}
+
+impl Laser {
+    fn new(x: usize, y: usize, direction: Direction) -> Self {
+        Self {
+            position: Point::from((x, y)),
+            direction,
+        }
+    }
+
+    fn advance(&mut self) {
+        self.position = self.position.step(self.direction);
+    }
+
+    /// Turn right and return a copy that went left, one step ahead.
+    fn split(&mut self) -> Laser {
+        let mut split = *self;
+        split.direction = self.direction.turn_left();
+        split.advance();
+
+        self.direction = self.direction.turn_right();
+        split
+    }
+}
+
+const TILES: &str = "./|\\-";
+
+/// Tiles the beam of `laser` passes through.
+fn energize(grid: &Grid<char>, laser: Laser) -> HashSet<Point> {
+    let mut energized = HashSet::new();
+    let mut laser_states = HashSet::new();
+
+    // lasers in progress
+    let mut pews = Vec::new();
+    pews.push(laser);
+
+    while let Some(mut laser) = pews.pop() {
+        while let Some(&tile) = grid.at(laser.position) {
+            if !laser_states.insert(laser) {
+                break;
+            }
+            energized.insert(laser.position);
+
+            match (tile, laser.direction) {
+                ('|', direction) if direction.is_horizontal() => pews.push(laser.split()),
+                ('-', direction) if direction.is_vertical() => pews.push(laser.split()),
+                ('/', direction) if direction.is_vertical() => {
+                    laser.direction = direction.turn_right()
+                }
+                ('/', direction) => laser.direction = direction.turn_left(),
+                ('\\', direction) if direction.is_vertical() => {
+                    laser.direction = direction.turn_left()
+                }
+                ('\\', direction) => laser.direction = direction.turn_right(),
+                _ => (), // move forward
+            }
+            laser.advance();
+        }
+    }
+    energized
+}
+
+/// Lasers entering from every edge tile, heading inwards.
+fn edge_lasers(grid: &Grid<char>) -> Vec<Laser> {
+    let (width, height) = (grid.width(), grid.height());
+    let mut lasers = Vec::new();
+    for x in 0..width {
+        lasers.push(Laser::new(x, 0, Direction::Down));
+        lasers.push(Laser::new(x, height - 1, Direction::Up));
+    }
+
+    for y in 0..height {
+        lasers.push(Laser::new(0, y, Direction::Right));
+        lasers.push(Laser::new(width - 1, y, Direction::Left));
+    }
+    lasers
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>> {
+    Day16::parse(input).and_then(|input| Day16::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<usize>> {
+    Day16::parse(input).and_then(|input| Day16::part_two(&input))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+
+    type Input<'a> = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |c| TILES.contains(c).then_some(c))
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(
+            energize(grid, Laser::new(0, 0, Direction::Right)).len(),
+        ))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Ok(edge_lasers(grid)
+            .par_iter()
+            .map(|laser| energize(grid, *laser).len())
+            .max())
+    }
+
+    /// Energized tiles, for the beam from the top left in part one and the best beam in part two.
+    fn visualize(grid: &Self::Input<'_>, part: u8) -> Result<Option<Canvas>> {
+        let energized = if part == 1 {
+            energize(grid, Laser::new(0, 0, Direction::Right))
+        } else {
+            edge_lasers(grid)
+                .par_iter()
+                .map(|laser| energize(grid, *laser))
+                .max_by_key(HashSet::len)
+                .unwrap_or_default()
+        };
+
+        let mut canvas = Canvas::from_grid(grid, |&tile| tile);
+        canvas.highlight(energized, Color::YELLOW);
+        Ok(Some(canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 16));
+        assert_eq!(result, Ok(Some(46)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 16));
+        assert_eq!(result, Ok(Some(51)));
+    }
+}
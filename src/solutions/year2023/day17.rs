@@ -0,0 +1,139 @@
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::{
+    visualize::{Canvas, Color},
+    Grid, Point,
+};
+use pathfinding::matrix::Matrix;
+use pathfinding::prelude::dijkstra;
+
+fn make_grid(input: &str) -> Result<Matrix<u32>> {
+    let heat_loss = Grid::parse(input, |character| character.to_digit(10))?;
+    if heat_loss.width() == 0 {
+        return Err(Error::solver("the map has no city blocks"));
+    }
+
+    Matrix::from_rows(heat_loss.rows().map(|row| row.iter().copied()))
+        .map_err(|e| Error::solver(e.to_string()))
+}
+
+/// Positions as `(row, column)` on the route with the least heat loss, and the heat lost on it.
+fn move_crucible(
+    grid: &Matrix<u32>,
+    min_move: usize,
+    max_move: usize,
+) -> Result<(Vec<(usize, usize)>, u32)> {
+    let (route, heat_loss) = dijkstra(
+        &((0, 0), (0, 0), 0),
+        |&(position, (horizontal_delta, vertical_delta), length)| {
+            let mut successors = Vec::new();
+            let mut build_successors = |direction, length| {
+                if let Some(space) = grid.move_in_direction(position, direction) {
+                    successors.push(((space, direction, length), grid[space]));
+                }
+            };
+
+            if length < max_move {
+                // check straight
+                build_successors((horizontal_delta, vertical_delta), length + 1);
+            }
+
+            if length >= min_move {
+                // check turns
+                build_successors((-vertical_delta, -horizontal_delta), 1);
+                build_successors((vertical_delta, horizontal_delta), 1);
+            } else if length == 0 {
+                // first space check right and down
+                build_successors((1, 0), 1);
+                build_successors((0, 1), 1);
+            }
+
+            successors
+        },
+        |&(position, _, length)| {
+            position == (grid.rows - 1, grid.columns - 1) && length >= min_move
+        },
+    )
+    .ok_or_else(|| Error::solver("no route reaches the factory"))?;
+
+    let positions = route.into_iter().map(|(position, _, _)| position).collect();
+    Ok((positions, heat_loss))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day17::parse(input).and_then(|input| Day17::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day17::parse(input).and_then(|input| Day17::part_two(&input))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+
+    type Input<'a> = Matrix<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        make_grid(input)
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(move_crucible(grid, 1, 3)?.1))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Ok(Some(move_crucible(grid, 4, 10)?.1))
+    }
+
+    /// Heat loss of every block and the route of the crucible, the ultra crucible in part two.
+    fn visualize(grid: &Self::Input<'_>, part: u8) -> Result<Option<Canvas>> {
+        let (route, _) = if part == 1 {
+            move_crucible(grid, 1, 3)
+        } else {
+            move_crucible(grid, 4, 10)
+        }?;
+
+        let mut canvas = Canvas::new(grid.columns, grid.rows);
+        for ((row, column), heat_loss) in grid.items() {
+            canvas.set(column, row, char::from_digit(*heat_loss, 10).unwrap_or('?'));
+            // the hotter, the brighter
+            let shade = 0x20 + 0x14 * (*heat_loss).min(9) as u8;
+            canvas.highlight(
+                [Point::from((column, row))],
+                Color::rgb(shade, shade / 2, 0),
+            );
+        }
+        canvas.path(
+            route
+                .into_iter()
+                .map(|(row, column)| Point::from((column, row))),
+            Color::RED,
+        );
+        Ok(Some(canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 17));
+        assert_eq!(result, Ok(Some(102)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 17));
+        assert_eq!(result, Ok(Some(94)));
+    }
+}
@@ -0,0 +1,209 @@
+use crate::template::{
+    error::{parse_at, split_once_at, Error, Result},
+    Solution,
+};
+use crate::utils::{
+    visualize::{Canvas, Color},
+    Direction, Point,
+};
+use itertools::Itertools;
+
+#[derive(Debug)]
+struct Instruction {
+    direction: Direction,
+    magnitude: u64,
+}
+
+fn trace_route(instructions: &Vec<Instruction>) -> Vec<Point> {
+    let mut perimeter: Vec<Point> = Vec::new();
+    let mut position = Point::ORIGIN;
+
+    for instruction in instructions {
+        position += instruction.direction.offset() * instruction.magnitude as i64;
+        perimeter.push(position)
+    }
+
+    perimeter
+}
+
+/// The plan as written: direction and length.
+fn dig_plan(input: &str) -> Result<Vec<Instruction>> {
+    input
+        .trim_end()
+        .lines()
+        .map(|line| {
+            let direction_color_split = split_once_at(input, line, " (#")?;
+            let direction_split = split_once_at(input, direction_color_split.0, " ")?;
+            let direction = direction_split
+                .0
+                .chars()
+                .next()
+                .and_then(Direction::from_letter)
+                .ok_or_else(|| Error::at(input, direction_split.0, "expected U, D, L or R"))?;
+            let magnitude = parse_at(input, direction_split.1)?;
+            Ok(Instruction {
+                magnitude,
+                direction,
+            })
+        })
+        .collect()
+}
+
+/// The plan hidden in the colours.
+fn hex_plan(input: &str) -> Result<Vec<Instruction>> {
+    input
+        .trim_end()
+        .lines()
+        .map(|line| {
+            let direction_why_are_elves_incompetent_split = split_once_at(input, line, " (#")?;
+            let color = direction_why_are_elves_incompetent_split.1;
+            let (Some(magnitude), Some(direction)) = (color.get(0..5), color.get(5..6)) else {
+                return Err(Error::at(input, color, "expected a colour of 6 hex digits"));
+            };
+            let direction = match direction {
+                "3" => Direction::Up,
+                "1" => Direction::Down,
+                "2" => Direction::Left,
+                "0" => Direction::Right,
+                _ => {
+                    return Err(Error::at(
+                        input,
+                        direction,
+                        format!("invalid direction '{direction}', expected 0 to 3"),
+                    ))
+                }
+            };
+            let magnitude = u64::from_str_radix(magnitude, 16).map_err(|e| {
+                Error::at(
+                    input,
+                    magnitude,
+                    format!("invalid value \"{magnitude}\": {e}"),
+                )
+            })?;
+            Ok(Instruction {
+                magnitude,
+                direction,
+            })
+        })
+        .collect()
+}
+
+/// Cubic meters of lava the lagoon dug by `instructions` holds.
+fn lagoon_size(instructions: &Vec<Instruction>) -> Result<i64> {
+    let mut route_coords = trace_route(instructions);
+
+    let perimeter: i64 = instructions
+        .iter()
+        .map(|instruction| instruction.magnitude as i64)
+        .sum();
+
+    let front_copy = *route_coords
+        .first()
+        .ok_or_else(|| Error::solver("the dig plan is empty"))?;
+    //seal up perimeter of points
+    route_coords.push(front_copy);
+
+    let mut reg_poly_area = 0;
+
+    for (coord1, coord2) in route_coords.into_iter().tuple_windows() {
+        reg_poly_area += (coord1.x - coord2.x) * (coord2.y + coord1.y);
+    }
+
+    Ok((reg_poly_area.abs() + perimeter) / 2 + 1)
+}
+
+pub fn part_one(input: &str) -> Result<Option<i64>> {
+    Day18::parse(input).and_then(|input| Day18::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<i64>> {
+    Day18::parse(input).and_then(|input| Day18::part_two(&input))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+
+    type Input<'a> = &'a str;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        lagoon_size(&dig_plan(input)?).map(Some)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        lagoon_size(&hex_plan(input)?).map(Some)
+    }
+
+    /// The trench around the lagoon and its inside. The huge lagoon of part two is scaled down to fit.
+    fn visualize(input: &Self::Input<'_>, part: u8) -> Result<Option<Canvas>> {
+        const MAX_SIZE: i64 = 400;
+
+        let instructions = if part == 1 {
+            dig_plan(input)
+        } else {
+            hex_plan(input)
+        }?;
+        let corners = trace_route(&instructions);
+
+        let Some((min_x, max_x)) = corners.iter().map(|c| c.x).minmax().into_option() else {
+            return Err(Error::solver("the dig plan is empty"));
+        };
+        let Some((min_y, max_y)) = corners.iter().map(|c| c.y).minmax().into_option() else {
+            return Err(Error::solver("the dig plan is empty"));
+        };
+        let scale = ((max_x - min_x).max(max_y - min_y) / MAX_SIZE).max(1);
+        let corners: Vec<Point> = corners
+            .iter()
+            .map(|c| Point::new((c.x - min_x) / scale, (c.y - min_y) / scale))
+            .collect();
+        let (width, height) = ((max_x - min_x) / scale + 1, (max_y - min_y) / scale + 1);
+
+        // fill each row between crossings of the vertical edges through the middle of its cells
+        let mut inside = Vec::new();
+        for y in 0..height {
+            let crossings: Vec<i64> = corners
+                .iter()
+                .zip(corners.iter().cycle().skip(1))
+                .filter(|(a, b)| a.x == b.x && a.y.min(b.y) <= y && y < a.y.max(b.y))
+                .map(|(a, _)| a.x)
+                .sorted()
+                .collect();
+            for pair in crossings.chunks(2) {
+                if let [from, to] = pair {
+                    inside.extend((*from..*to).map(|x| Point::new(x, y)));
+                }
+            }
+        }
+
+        let mut canvas = Canvas::new(width as usize, height as usize);
+        canvas.highlight(inside, Color::BLUE);
+        canvas.polygon(corners, Color::RED);
+        Ok(Some(canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 18));
+        assert_eq!(result, Ok(Some(62)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 18));
+        assert_eq!(result, Ok(Some(952408144115)));
+    }
+}
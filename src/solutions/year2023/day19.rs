@@ -0,0 +1,341 @@
+use self::Workflow::{Accept, Goto, Greater, Less, Reject};
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::parse::{integer, lines, parse_all, record, ParseResult};
+use crate::utils::IntervalSet;
+use nom::branch::alt;
+use nom::character::complete::{alpha1, char, line_ending, one_of};
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, separated_pair, tuple};
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Workflow<'a> {
+    Accept,
+    Reject,
+    Goto(&'a str),                  // (label)
+    Greater(&'a str, u64, &'a str), // (rating label, comparison value, label)
+    Less(&'a str, u64, &'a str),    // (rating label, comparison value, label)
+}
+
+type Workflows<'a> = HashMap<&'a str, Vec<Workflow<'a>>>;
+type Ratings<'a> = HashMap<&'a str, u64>;
+
+/// Rating categories, in the order of the ranges in part two.
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+fn combinations(ranges: &[IntervalSet; 4]) -> u64 {
+    ranges.iter().map(IntervalSet::len).product()
+}
+
+fn parse_rule(input: &str) -> ParseResult<'_, Workflow<'_>> {
+    alt((
+        map(
+            tuple((alpha1, one_of("<>"), integer, preceded(char(':'), alpha1))),
+            |(category, comparison, value, label)| match comparison {
+                '<' => Less(category, value, label),
+                _ => Greater(category, value, label),
+            },
+        ),
+        map(alpha1, |label| match label {
+            "A" => Accept,
+            "R" => Reject,
+            label => Goto(label),
+        }),
+    ))(input)
+}
+
+fn parse_workflow(input: &str) -> ParseResult<'_, (&str, Vec<Workflow<'_>>)> {
+    record(alpha1, separated_list1(char(','), parse_rule))(input)
+}
+
+fn parse_ratings(input: &str) -> ParseResult<'_, Ratings<'_>> {
+    map(
+        delimited(
+            char('{'),
+            separated_list1(char(','), separated_pair(alpha1, char('='), integer)),
+            char('}'),
+        ),
+        |ratings| ratings.into_iter().collect(),
+    )(input)
+}
+
+/// Check that the parts can be sorted: there is an `in` workflow, every workflow ends in a rule without a condition,
+/// rules only send parts to workflows that exist and only compare known categories, and every part has all ratings.
+fn check(workflows: &Workflows, gears: &[Ratings]) -> Result<()> {
+    if !workflows.contains_key("in") {
+        return Err(Error::solver("there is no workflow \"in\""));
+    }
+
+    for (label, rules) in workflows {
+        if !matches!(rules.last(), Some(Accept | Reject | Goto(_))) {
+            return Err(Error::solver(format!(
+                "workflow \"{label}\" does not end in a rule without a condition"
+            )));
+        }
+
+        for rule in rules {
+            let target = match *rule {
+                Accept | Reject => continue,
+                Goto(target) => target,
+                Greater(category, _, target) | Less(category, _, target) => {
+                    if !CATEGORIES.contains(&category) {
+                        return Err(Error::solver(format!(
+                            "workflow \"{label}\" compares the unknown category \"{category}\""
+                        )));
+                    }
+                    target
+                }
+            };
+            if !matches!(target, "A" | "R") && !workflows.contains_key(target) {
+                return Err(Error::solver(format!(
+                    "workflow \"{label}\" sends parts to the undefined workflow \"{target}\""
+                )));
+            }
+        }
+    }
+
+    for (i, gear) in gears.iter().enumerate() {
+        if let Some(category) = CATEGORIES
+            .iter()
+            .find(|&category| !gear.contains_key(category))
+        {
+            return Err(Error::solver(format!(
+                "part {} has no \"{category}\" rating",
+                i + 1
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse(input: &str) -> Result<(Workflows<'_>, Vec<Ratings<'_>>)> {
+    let (workflows, gears) = parse_all(
+        input,
+        map(
+            separated_pair(
+                lines(parse_workflow),
+                pair(line_ending, line_ending),
+                lines(parse_ratings),
+            ),
+            |(workflows, gears)| (workflows.into_iter().collect(), gears),
+        ),
+    )?;
+    check(&workflows, &gears)?;
+    Ok((workflows, gears))
+}
+
+/// The rules of the workflow `label`, which [`check`] made sure exists.
+fn rules<'w, 'a>(workflows: &'w Workflows<'a>, label: &str) -> Result<&'w [Workflow<'a>]> {
+    workflows
+        .get(label)
+        .map(Vec::as_slice)
+        .ok_or_else(|| Error::solver(format!("there is no workflow \"{label}\"")))
+}
+
+/// The rating of `gear` in `category`, which [`check`] made sure it has.
+fn rating(gear: &Ratings, category: &str) -> Result<u64> {
+    gear.get(category)
+        .copied()
+        .ok_or_else(|| Error::solver(format!("a part has no \"{category}\" rating")))
+}
+
+/// Position of `category` in [`CATEGORIES`].
+fn category_index(category: &str) -> Result<usize> {
+    CATEGORIES
+        .iter()
+        .position(|&c| c == category)
+        .ok_or_else(|| Error::solver(format!("unknown category \"{category}\"")))
+}
+
+pub fn part_one(input: &str) -> Result<Option<u64>> {
+    Day19::parse(input).and_then(|input| Day19::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>> {
+    Day19::parse(input).and_then(|input| Day19::part_two(&input))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+
+    type Input<'a> = (Workflows<'a>, Vec<Ratings<'a>>);
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let (workflow, gears) = input;
+
+        let mut accepted = Vec::new();
+
+        'gears: for gear in gears {
+            let mut workflow_label = "in";
+            'workflow: loop {
+                if workflow_label == "A" {
+                    accepted.push(gear.clone());
+                    continue 'gears;
+                }
+
+                if workflow_label == "R" {
+                    continue 'gears;
+                }
+
+                for rule in rules(workflow, workflow_label)? {
+                    match rule {
+                        Accept => {
+                            accepted.push(gear.clone());
+                            continue 'gears;
+                        }
+                        Reject => {
+                            continue 'gears;
+                        }
+                        Goto(label) => {
+                            workflow_label = label;
+                            continue 'workflow;
+                        }
+                        Greater(property, value, label) => {
+                            if rating(gear, property)? > *value {
+                                workflow_label = label;
+                                continue 'workflow;
+                            }
+                        }
+                        Less(property, value, label) => {
+                            if rating(gear, property)? < *value {
+                                workflow_label = label;
+                                continue 'workflow;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Some(
+            accepted
+                .iter()
+                .map(|entry| entry.values().sum::<u64>())
+                .sum(),
+        ))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let (workflow, _) = input;
+
+        let mut stack = Vec::new();
+        let mut accepted = 0;
+
+        let ranges: [IntervalSet; 4] = std::array::from_fn(|_| IntervalSet::from(1..=4000));
+        stack.push((ranges, "in", 0));
+
+        while let Some((ranges, workflow_label, rule_key)) = stack.pop() {
+            // ensure no range has been split away entirely
+            if ranges.iter().any(IntervalSet::is_empty) {
+                continue;
+            }
+
+            // check if at the end of a workflow chain
+            if workflow_label == "A" {
+                accepted += combinations(&ranges);
+                continue;
+            } else if workflow_label == "R" {
+                continue;
+            }
+
+            let rule = *rules(workflow, workflow_label)?
+                .get(rule_key)
+                .ok_or_else(|| {
+                    Error::solver(format!(
+                        "workflow \"{workflow_label}\" does not end in a rule without a condition"
+                    ))
+                })?;
+
+            let (property, matching, rest, label) = match rule {
+                Accept => {
+                    accepted += combinations(&ranges);
+                    continue;
+                }
+                Reject => {
+                    continue;
+                }
+                Goto(label) => {
+                    stack.push((ranges, label, 0));
+                    continue;
+                }
+                Greater(property, value, label) => {
+                    let i = category_index(property)?;
+                    let (below, above) = ranges[i].split_at(value as i64 + 1);
+                    (i, above, below, label)
+                }
+                Less(property, value, label) => {
+                    let i = category_index(property)?;
+                    let (below, above) = ranges[i].split_at(value as i64);
+                    (i, below, above, label)
+                }
+            };
+
+            let mut matched = ranges.clone();
+            matched[property] = matching;
+            stack.push((matched, label, 0));
+
+            let mut unmatched = ranges;
+            unmatched[property] = rest;
+            stack.push((unmatched, workflow_label, rule_key + 1));
+        }
+
+        Ok(Some(accepted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 19));
+        assert_eq!(result, Ok(Some(19114)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 19));
+        assert_eq!(result, Ok(Some(167409079868000)));
+    }
+
+    #[test]
+    fn test_invalid_workflows() {
+        let error = |input: &str| match Day19::parse(input) {
+            Err(Error::Solver(message)) => message,
+            result => panic!("expected a solver error, got {result:?}"),
+        };
+
+        assert_eq!(
+            error("in{x<10:ab,R}\n\n{x=1,m=2,a=3,s=4}\n"),
+            "workflow \"in\" sends parts to the undefined workflow \"ab\""
+        );
+        assert_eq!(
+            error("in{x<10:A}\n\n{x=1,m=2,a=3,s=4}\n"),
+            "workflow \"in\" does not end in a rule without a condition"
+        );
+        assert_eq!(
+            error("in{q<10:A,R}\n\n{x=1,m=2,a=3,s=4}\n"),
+            "workflow \"in\" compares the unknown category \"q\""
+        );
+        assert_eq!(
+            error("in{x<10:A,R}\n\n{x=1,m=2,a=3}\n"),
+            "part 1 has no \"s\" rating"
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use crate::template::{
+    error::{Error, Result},
+    Parameters, Solution,
+};
+use crate::utils::{Circuit, Rng};
+#[cfg(test)]
+use std::collections::{HashMap, VecDeque};
+
+pub fn part_one(input: &str) -> Result<Option<u64>> {
+    Day20::parse(input).and_then(|puzzle| Day20::part_one(&puzzle))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u64>> {
+    Day20::parse(input).and_then(|puzzle| Day20::part_two(&puzzle))
+}
+
+fn count_pulses(circuit: &Circuit, presses: usize) -> u64 {
+    let mut simulation = circuit.simulate();
+    for _ in 0..presses {
+        simulation.press();
+    }
+
+    let total = simulation.total();
+    total.low * total.high
+}
+
+/// Number of button presses until `rx` receives a low pulse, if the circuit has an `rx` module.
+fn count_presses(circuit: &Circuit) -> Result<Option<u64>> {
+    let Some(rx) = circuit.index("rx") else {
+        return Ok(None);
+    };
+    circuit
+        .first_low_pulse(rx)?
+        .map(Some)
+        .ok_or_else(|| Error::solver("rx never receives a low pulse"))
+}
+
+pub struct Puzzle<'a> {
+    circuit: Circuit<'a>,
+    /// Button presses in part one.
+    presses: usize,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Pulse Propagation";
+
+    type Input<'a> = Puzzle<'a>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Input<'a>> {
+        Ok(Puzzle {
+            circuit: Circuit::parse(input)?,
+            presses: parameters.get_or("presses", 1000)?,
+        })
+    }
+
+    fn part_one(puzzle: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(count_pulses(&puzzle.circuit, puzzle.presses)))
+    }
+
+    fn part_two(puzzle: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        count_presses(&puzzle.circuit)
+    }
+
+    /// Binary counters like those of real inputs, up to three of them with `2 + size / 4` bits or more, combined into
+    /// `rx` in one of a few ways: by a single conjunction, by one conjunction per group of counters, or by nested ones.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut used = vec!["rx".to_string()];
+        let mut name = |rng: &mut Rng| loop {
+            let name: String = (0..2)
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect();
+            if !used.contains(&name) {
+                used.push(name.clone());
+                return name;
+            }
+        };
+
+        let mut lines = Vec::new();
+        let mut starts = Vec::new();
+        let mut inverters = Vec::new();
+        for _ in 0..1 + rng.below(3) {
+            let bits = (2 + size / 4 + rng.below(2) as usize).min(12);
+            // odd and with the top bit set, so that the counter resets to zero when it reaches it
+            let period = (1 << (bits - 1)) + rng.below(1 << (bits - 2)) * 2 + 1;
+
+            let flip_flops: Vec<String> = (0..bits).map(|_| name(rng)).collect();
+            let (conjunction, inverter) = (name(rng), name(rng));
+            let mut resets = vec![flip_flops[0].clone()];
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut destinations: Vec<&str> = flip_flops
+                    .get(bit + 1)
+                    .map(String::as_str)
+                    .into_iter()
+                    .collect();
+                if period & (1 << bit) != 0 {
+                    destinations.push(&conjunction);
+                } else {
+                    resets.push(flip_flop.clone());
+                }
+                lines.push(format!("%{flip_flop} -> {}", destinations.join(", ")));
+            }
+            resets.push(inverter.clone());
+            lines.push(format!("&{conjunction} -> {}", resets.join(", ")));
+
+            starts.push(flip_flops[0].clone());
+            inverters.push(inverter);
+        }
+
+        // a conjunction of `inputs`, which have no other destinations
+        let combine = |lines: &mut Vec<String>, inputs: &[String], conjunction: &str| {
+            for input in inputs {
+                lines.push(format!("&{input} -> {conjunction}"));
+            }
+        };
+        match rng.below(3) {
+            1 if inverters.len() > 1 => {
+                let split = 1 + rng.below(inverters.len() as u64 - 1) as usize;
+                for group in [&inverters[..split], &inverters[split..]] {
+                    let conjunction = name(rng);
+                    combine(&mut lines, group, &conjunction);
+                    lines.push(format!("&{conjunction} -> rx"));
+                }
+            }
+            2 if inverters.len() > 1 => {
+                let (pair, inverter, conjunction) = (name(rng), name(rng), name(rng));
+                combine(&mut lines, &inverters[..2], &pair);
+                lines.push(format!("&{pair} -> {inverter}"));
+                combine(&mut lines, &inverters[2..], &conjunction);
+                lines.push(format!("&{inverter} -> {conjunction}"));
+                lines.push(format!("&{conjunction} -> rx"));
+            }
+            _ => {
+                let conjunction = name(rng);
+                combine(&mut lines, &inverters, &conjunction);
+                lines.push(format!("&{conjunction} -> rx"));
+            }
+        }
+
+        lines.push(format!("broadcaster -> {}", starts.join(", ")));
+        rng.shuffle(&mut lines);
+        Some(lines.join("\n") + "\n")
+    }
+}
+
+/// Keeps modules in a map by name and presses the button until `rx` gets a low pulse, which the circuit model and
+/// the sub-counter analysis were checked against.
+#[cfg(test)]
+pub struct Day20Reference;
+
+#[cfg(test)]
+impl Day20Reference {
+    /// Press the button `presses` times, or until `rx` receives a low pulse if there are none. Returns the low and
+    /// high pulses sent and the number of presses.
+    fn press(input: &str, presses: Option<u64>) -> (u64, u64, u64) {
+        let mut destinations: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut kinds: HashMap<&str, u8> = HashMap::new();
+        for line in input.lines() {
+            let (module, outputs) = line.split_once(" -> ").unwrap();
+            let (kind, name) = match module.as_bytes()[0] {
+                b'%' | b'&' => (module.as_bytes()[0], &module[1..]),
+                _ => (b'b', module),
+            };
+            kinds.insert(name, kind);
+            destinations.insert(name, outputs.split(", ").collect());
+        }
+        let mut on: HashMap<&str, bool> = HashMap::new();
+        let mut memory: HashMap<&str, HashMap<&str, bool>> = HashMap::new();
+        for (&from, outputs) in &destinations {
+            for &to in outputs {
+                memory.entry(to).or_default().insert(from, false);
+            }
+        }
+
+        let (mut lows, mut highs) = (0, 0);
+        for press in 1.. {
+            let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+            while let Some((from, to, high)) = queue.pop_front() {
+                if high {
+                    highs += 1;
+                } else {
+                    lows += 1;
+                    if to == "rx" && presses.is_none() {
+                        return (lows, highs, press);
+                    }
+                }
+
+                let sent = match kinds.get(to) {
+                    Some(b'b') => Some(high),
+                    Some(b'%') if !high => {
+                        let state = on.entry(to).or_default();
+                        *state = !*state;
+                        Some(*state)
+                    }
+                    Some(b'&') => {
+                        let inputs = memory.get_mut(to).unwrap();
+                        inputs.insert(from, high);
+                        Some(!inputs.values().all(|&high| high))
+                    }
+                    _ => None,
+                };
+                if let Some(high) = sent {
+                    for &next in &destinations[to] {
+                        queue.push_back((to, next, high));
+                    }
+                }
+            }
+            if presses == Some(press) {
+                return (lows, highs, press);
+            }
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+impl Solution for Day20Reference {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Pulse Propagation";
+
+    type Input<'a> = &'a str;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let (lows, highs, _) = Self::press(input, Some(1000));
+        Ok(Some(lows * highs))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        if !input.contains("rx") {
+            return Ok(None);
+        }
+        Ok(Some(Self::press(input, None).2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 20));
+        assert_eq!(result, Ok(Some(32000000)));
+    }
+
+    #[test]
+    fn test_part_one_presses() {
+        let input = crate::template::read_file("examples", 2023, 20);
+        let puzzle = Day20::parse_with(&input, &Parameters::new().with("presses", 1));
+        let result = puzzle.and_then(|puzzle| Day20::part_one(&puzzle));
+        assert_eq!(result, Ok(Some(32)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 20));
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::template::examples::check::<Day20>();
+    }
+
+    #[test]
+    fn test_reference() {
+        crate::template::differential::check::<Day20, Day20Reference>(30, 8);
+    }
+}
@@ -0,0 +1,348 @@
+use crate::template::{
+    error::{parse_at, split_once_at, Error, Result},
+    Solution,
+};
+use crate::utils::{
+    trace,
+    visualize::{Canvas, Color},
+    Point, Rng,
+};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+struct Brick {
+    x1: u32,
+    y1: u32,
+    z1: u32,
+    x2: u32,
+    y2: u32,
+    z2: u32,
+    id: usize,
+    bricks_below: Vec<usize>,
+    bricks_above: Vec<usize>,
+}
+
+impl Brick {
+    /// Parse `line`, a line of `input`.
+    fn new(input: &str, line: &str) -> Result<Self> {
+        let (start, end) = split_once_at(input, line, "~")?;
+        let coords: Vec<u32> = [start, end]
+            .into_iter()
+            .flat_map(|end| end.split(','))
+            .map(|num| parse_at(input, num))
+            .collect::<Result<_>>()?;
+        let &[x1, y1, z1, x2, y2, z2] = coords.as_slice() else {
+            return Err(Error::at(input, line, "expected x,y,z~x,y,z"));
+        };
+        if x1.max(x2) > 9 || y1.max(y2) > 9 {
+            return Err(Error::at(
+                input,
+                line,
+                "brick lies outside the 10 by 10 area",
+            ));
+        }
+
+        Ok(Self {
+            x1: x1.min(x2),
+            y1: y1.min(y2),
+            z1,
+            x2: x1.max(x2),
+            y2: y1.max(y2),
+            z2,
+            id: 0,
+            bricks_below: Vec::with_capacity(10),
+            bricks_above: Vec::with_capacity(10),
+        })
+    }
+
+    fn set_id(&mut self, id: usize) {
+        self.id = id;
+    }
+
+    fn lowest_z(&self) -> u32 {
+        self.z1.min(self.z2)
+    }
+
+    fn highest_z(&self) -> u32 {
+        self.z1.max(self.z2)
+    }
+
+    fn can_remove(&self, bricks: &[Brick]) -> bool {
+        for &brick in &self.bricks_above {
+            if bricks[brick].bricks_below.len() == 1 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn will_fall(&self, falling: &[bool]) -> bool {
+        self.bricks_below.iter().all(|&brick| falling[brick])
+    }
+
+    fn drop(&mut self, height_shadow: &mut [[Option<usize>; 10]; 10], bricks: &mut [Brick]) {
+        let mut below = Vec::new();
+        let mut new_height = 0;
+
+        for column in &mut height_shadow[self.x1 as usize..=self.x2 as usize] {
+            for shadow in &mut column[self.y1 as usize..=self.y2 as usize] {
+                if let Some(id) = *shadow {
+                    if !below.contains(&id) {
+                        new_height = new_height.max(bricks[id].highest_z());
+                        below.push(id);
+                    }
+                }
+                *shadow = Some(self.id);
+            }
+        }
+
+        // update the references for surrounding bricks
+        for id in below {
+            if bricks[id].highest_z() == new_height {
+                self.bricks_below.push(id);
+                bricks[id].bricks_above.push(self.id);
+            }
+        }
+
+        if self.z1 < self.z2 {
+            let d = self.z2 - self.z1;
+            self.z1 = new_height + 1;
+            self.z2 = self.z1 + d;
+        } else {
+            let d = self.z1 - self.z2;
+            self.z2 = new_height + 1;
+            self.z1 = self.z2 + d;
+        }
+    }
+}
+
+/// The bricks seen from the side, with x across and z up. Bricks in front hide the ones behind them.
+fn side_view(bricks: &[Brick]) -> Canvas {
+    const COLORS: [Color; 5] = [
+        Color::RED,
+        Color::GREEN,
+        Color::BLUE,
+        Color::YELLOW,
+        Color::WHITE,
+    ];
+
+    let top = bricks.iter().map(Brick::highest_z).max().unwrap_or(0) as usize;
+    let mut canvas = Canvas::new(10, top + 1);
+    for x in 0..10 {
+        canvas.set(x, top, '=');
+    }
+
+    let mut order: Vec<_> = bricks.iter().collect();
+    order.sort_by_key(|brick| std::cmp::Reverse(brick.y1.min(brick.y2)));
+    for brick in order {
+        let cells: Vec<_> = (brick.x1.min(brick.x2)..=brick.x1.max(brick.x2))
+            .flat_map(|x| (brick.lowest_z()..=brick.highest_z()).map(move |z| (x, z)))
+            .map(|(x, z)| Point::new(x.into(), top as i64 - i64::from(z)))
+            .collect();
+        for cell in &cells {
+            canvas.set(cell.x as usize, cell.y as usize, '#');
+        }
+        canvas.highlight(cells, COLORS[brick.id % COLORS.len()]);
+    }
+    canvas
+}
+
+/// Parse the bricks and let them fall until they rest on the ground or on each other.
+fn settle(input: &str) -> Result<Vec<Brick>> {
+    let mut bricks: Vec<Brick> = input
+        .trim_end()
+        .lines()
+        .map(|line| Brick::new(input, line))
+        .collect::<Result<_>>()?;
+
+    let mut height_shadow: [[Option<usize>; 10]; 10] = [[None; 10]; 10];
+
+    // sort bricks by initial lowest z position
+    bricks.sort_by_key(|brick| brick.lowest_z());
+    bricks
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, brick)| brick.set_id(i));
+
+    //drop the bricks
+    for i in 0..bricks.len() {
+        let mut brick = bricks[i].clone();
+        brick.drop(&mut height_shadow, &mut bricks);
+        bricks[i] = brick;
+        trace::frame(|| side_view(&bricks));
+    }
+
+    Ok(bricks)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day22::parse(input).and_then(|input| Day22::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day22::parse(input).and_then(|input| Day22::part_two(&input))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Sand Slabs";
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let bricks = settle(input)?;
+
+        let mut count = 0;
+        for i in 0..bricks.len() {
+            if bricks[i].can_remove(&bricks) {
+                count += 1;
+            }
+        }
+
+        Ok(Some(count))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        let bricks = settle(input)?;
+
+        let mut falling_bricks = vec![false; bricks.len()];
+        let mut queue = VecDeque::new();
+        let mut count = 0;
+
+        for brick in &bricks {
+            // set brick to falling to try starting a chain reaction
+            falling_bricks[brick.id] = true;
+
+            queue.push_back(brick.id);
+
+            // search up the tree via bricks above our current
+            while let Some(brick) = queue.pop_front() {
+                for above_brick in &bricks[brick].bricks_above {
+                    if !falling_bricks[*above_brick]
+                        && bricks[*above_brick].will_fall(&falling_bricks)
+                    {
+                        falling_bricks[*above_brick] = true;
+                        queue.push_back(*above_brick);
+                        count += 1;
+                    }
+                }
+            }
+
+            // reset falling status
+            falling_bricks.fill(false);
+        }
+
+        Ok(Some(count))
+    }
+
+    /// `size` bricks of up to 4 cubes on the 10 by 10 area of real inputs, floating in the air in random order.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut z = 1;
+        let mut bricks: Vec<_> = (0..size)
+            .map(|_| {
+                let mut start = [rng.below(10), rng.below(10), z + rng.below(3)];
+                let mut end = start;
+                let axis = rng.below(3) as usize;
+                end[axis] += rng.below(4);
+                if end[axis] > 9 && axis < 2 {
+                    // stay inside the area by growing the other way
+                    start[axis] -= end[axis] - 9;
+                    end[axis] = 9;
+                }
+                // every brick starts above the previous one, so that no two overlap
+                z = end[2] + 1;
+
+                format!(
+                    "{},{},{}~{},{},{}",
+                    start[0], start[1], start[2], end[0], end[1], end[2]
+                )
+            })
+            .collect();
+        rng.shuffle(&mut bricks);
+
+        Some(bricks.join("\n") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 22));
+        assert_eq!(result, Ok(Some(5)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 22));
+        assert_eq!(result, Ok(Some(7)));
+    }
+
+    fn cubes(brick: &[u32; 6]) -> impl Iterator<Item = (u32, u32, u32)> + '_ {
+        (brick[0]..=brick[3]).flat_map(move |x| {
+            (brick[1]..=brick[4]).flat_map(move |y| (brick[2]..=brick[5]).map(move |z| (x, y, z)))
+        })
+    }
+
+    /// Let bricks fall one level at a time until none can, returning how many of them moved.
+    fn settle(bricks: &mut [[u32; 6]]) -> usize {
+        let mut moved = vec![false; bricks.len()];
+        'fall: loop {
+            for i in 0..bricks.len() {
+                let others: HashSet<_> = (0..bricks.len())
+                    .filter(|&j| j != i)
+                    .flat_map(|j| cubes(&bricks[j]).collect::<Vec<_>>())
+                    .collect();
+                let can_fall = bricks[i][2] > 1
+                    && cubes(&bricks[i]).all(|(x, y, z)| !others.contains(&(x, y, z - 1)));
+                if can_fall {
+                    bricks[i][2] -= 1;
+                    bricks[i][5] -= 1;
+                    moved[i] = true;
+                    continue 'fall;
+                }
+            }
+            return moved.iter().filter(|&&moved| moved).count();
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day22::generate(&mut Rng::new(seed), 12).unwrap();
+            let mut bricks: Vec<[u32; 6]> = input
+                .lines()
+                .map(|line| {
+                    let coordinates: Vec<_> =
+                        line.split([',', '~']).map(|n| n.parse().unwrap()).collect();
+                    coordinates.try_into().unwrap()
+                })
+                .collect();
+            settle(&mut bricks);
+
+            let falling: Vec<_> = (0..bricks.len())
+                .map(|i| {
+                    let mut rest = bricks.clone();
+                    rest.remove(i);
+                    settle(&mut rest) as u32
+                })
+                .collect();
+
+            let removable = falling.iter().filter(|&&count| count == 0).count() as u32;
+            assert_eq!(part_one(&input), Ok(Some(removable)), "{input}");
+            assert_eq!(part_two(&input), Ok(Some(falling.iter().sum())), "{input}");
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::template::{
+    error::{Error, Result},
+    Solution,
+};
+use crate::utils::graph::compress_grid;
+use crate::utils::{
+    visualize::{Canvas, Color},
+    Direction, Grid, Point,
+};
+use itertools::Itertools;
+
+const TILES: &str = ".#^>v<";
+
+type Tiles = Vec<(usize, usize)>;
+
+#[derive(Debug)]
+pub struct Map {
+    start: (usize, usize),
+    end: (usize, usize),
+    grid: Grid<char>,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse(input, |c| TILES.contains(c).then_some(c))?;
+        if grid.height() < 2 || grid.width() < 3 {
+            return Err(Error::solver("the map is too small to walk through"));
+        }
+
+        let start = (1, 0);
+        let end = (grid.width() - 2, grid.height() - 1);
+        Ok(Self { start, end, grid })
+    }
+
+    /// Longest hike on the graph of junctions, where `can_step` decides which steps are allowed.
+    fn longest_path(
+        &self,
+        can_step: impl Fn((usize, usize), (usize, usize)) -> bool,
+    ) -> Option<u32> {
+        let (graph, positions) = compress_grid(&self.grid, |&c| c != '#', can_step);
+        let node = |position| positions.iter().position(|&p| p == position);
+
+        let length = graph.longest_path(node(self.start)?, node(self.end)?)?;
+        Some(length as u32)
+    }
+
+    /// Tiles of the longest hike from the start to the end, and the junctions it can choose at.
+    fn longest_hike(
+        &self,
+        can_step: impl Fn((usize, usize), (usize, usize)) -> bool,
+    ) -> Option<(Tiles, Tiles)> {
+        let (graph, positions) = compress_grid(&self.grid, |&c| c != '#', &can_step);
+        let node = |position| positions.iter().position(|&p| p == position);
+
+        let (_, route) = graph.longest_route(node(self.start)?, node(self.end)?)?;
+        let mut tiles = vec![self.start];
+        for pair in route.windows(2) {
+            tiles.extend(self.corridor(
+                positions[pair[0]],
+                positions[pair[1]],
+                &positions,
+                &can_step,
+            ));
+        }
+        Some((tiles, positions))
+    }
+
+    /// Tiles of the longest corridor from junction `from` to junction `to`, without `from`.
+    fn corridor(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+        junctions: &[(usize, usize)],
+        can_step: impl Fn((usize, usize), (usize, usize)) -> bool,
+    ) -> Tiles {
+        let open_neighbours = |(x, y)| {
+            self.grid
+                .neighbours(x, y)
+                .filter(|&position| self.grid[position] != '#')
+        };
+
+        open_neighbours(from)
+            .filter_map(|first| {
+                let (mut previous, mut current) = (from, first);
+                let mut tiles = vec![first];
+                while !junctions.contains(&current) {
+                    let next = open_neighbours(current).find(|&next| next != previous)?;
+                    (previous, current) = (current, next);
+                    tiles.push(current);
+                }
+                (current == to).then_some(tiles)
+            })
+            // corridors that cannot be walked towards `to`, like up a slope, are no part of the hike
+            .filter(|tiles| {
+                std::iter::once(&from)
+                    .chain(tiles)
+                    .tuple_windows()
+                    .all(|(&a, &b)| can_step(a, b))
+            })
+            .max_by_key(Vec::len)
+            .unwrap_or_default()
+    }
+
+    /// Slopes can only be walked downhill: stepping off a slope goes in its direction, stepping onto one must not go against it.
+    fn is_downhill(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        let direction = Direction::between(Point::from(from), Point::from(to));
+
+        let leaves_slope =
+            Direction::from_arrow(self.grid[from]).is_none_or(|slope| Some(slope) == direction);
+        let enters_slope = Direction::from_arrow(self.grid[to])
+            .is_none_or(|slope| Some(slope.reverse()) != direction);
+        leaves_slope && enters_slope
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day23::parse(input).and_then(|input| Day23::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day23::parse(input).and_then(|input| Day23::part_two(&input))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "A Long Walk";
+
+    type Input<'a> = Map;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Map::parse(input)
+    }
+
+    fn part_one(map: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(map.longest_path(|from, to| map.is_downhill(from, to)))
+    }
+
+    fn part_two(map: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        // the slopes are not that slippery after all
+        Ok(map.longest_path(|_, _| true))
+    }
+
+    /// The longest hike and the junctions on the map, down the slopes in part one and across them in part two.
+    fn visualize(map: &Self::Input<'_>, part: u8) -> Result<Option<Canvas>> {
+        let hike = if part == 1 {
+            map.longest_hike(|from, to| map.is_downhill(from, to))
+        } else {
+            map.longest_hike(|_, _| true)
+        };
+        let Some((tiles, junctions)) = hike else {
+            return Err(Error::solver("there is no hike to the end"));
+        };
+
+        let mut canvas = Canvas::from_grid(&map.grid, |&tile| tile);
+        canvas.path(tiles.into_iter().map(Point::from), Color::RED);
+        canvas.highlight(junctions.into_iter().map(Point::from), Color::GREEN);
+        Ok(Some(canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 23));
+        assert_eq!(result, Ok(Some(94)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 23));
+        assert_eq!(result, Ok(Some(154)));
+    }
+}
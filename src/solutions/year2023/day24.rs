@@ -0,0 +1,309 @@
+use crate::template::{
+    error::{parse_at, split_once_at, Error, Result},
+    Parameters, Solution,
+};
+use crate::utils::number_theory::gcd;
+use std::ops::{RangeInclusive, Sub};
+
+/// Bounds of the test area of the real input, in both x and y.
+const MIN: f64 = 200000000000000f64;
+const MAX: f64 = 400000000000000f64;
+
+#[derive(Debug)]
+struct Hailstone {
+    point: (f64, f64, f64),  // (x, y, z)
+    vector: (f64, f64, f64), // (dx, dy, dz)
+}
+
+type Vector3 = nalgebra::Vector3<i128>;
+
+#[derive(Debug, Copy, Clone)]
+struct Line {
+    point: Vector3,
+    vector: Vector3,
+}
+
+impl Line {
+    /// Parse `line`, a line of `input`, like `19, 13, 30 @ -2, 1, -2`.
+    fn new(input: &str, line: &str) -> Result<Self> {
+        let (point, vector) = split_once_at(input, line, " @ ")?;
+        Ok(Line {
+            point: parse_vector3(input, point)?,
+            vector: parse_vector3(input, vector)?,
+        })
+    }
+}
+
+impl From<&Line> for Hailstone {
+    fn from(line: &Line) -> Self {
+        let float = |vector: Vector3| (vector.x as f64, vector.y as f64, vector.z as f64);
+        Hailstone {
+            point: float(line.point),
+            vector: float(line.vector),
+        }
+    }
+}
+
+impl Sub for &Line {
+    type Output = Line;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Line {
+            point: self.point - rhs.point,
+            vector: self.vector - rhs.vector,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Plane {
+    normal: Vector3,
+    constant: i128,
+}
+
+impl Plane {
+    fn intersect(a: &Self, b: &Self, c: &Self) -> Option<Vector3> {
+        let denominator = a.normal.dot(&b.normal.cross(&c.normal));
+        // if the denominator is 0 then all vectors involved are non intersecting (Shouldn't happen for this problem set)
+        if denominator == 0 {
+            return None;
+        }
+
+        Some(
+            (b.normal.cross(&c.normal) * a.constant
+                + c.normal.cross(&a.normal) * b.constant
+                + a.normal.cross(&b.normal) * c.constant)
+                / denominator,
+        )
+    }
+
+    fn reduce(&mut self) {
+        // these numbers are huge and prone to overflowing, try to keep them manageable
+        let gcd = [self.normal.x, self.normal.y, self.normal.z]
+            .into_iter()
+            .try_fold(self.constant, gcd);
+        // zero for a plane without a normal, and `None` if it overflows: both leave nothing to divide by
+        if let Some(gcd) = gcd.filter(|&gcd| gcd > 1) {
+            self.normal /= gcd;
+            self.constant /= gcd;
+        }
+    }
+}
+
+/// Parse the three comma separated coordinates of `fragment`, a slice of `input`.
+fn parse_vector3(input: &str, fragment: &str) -> Result<Vector3> {
+    let coords = fragment
+        .split(',')
+        .map(|coord| parse_at(input, coord.trim()))
+        .collect::<Result<Vec<_>>>()?;
+    match coords[..] {
+        [x, y, z] => Ok(Vector3::new(x, y, z)),
+        _ => Err(Error::at(
+            input,
+            fragment,
+            format!("expected 3 coordinates, got {}", coords.len()),
+        )),
+    }
+}
+
+// Construct plane of velocities that would hit both hailstones: i.e. their intersection plane
+fn find_rock_velocities(a: &Line, b: &Line) -> Plane {
+    // simplify frame of reference to have b stationary at the origin
+    // and a is moving relative to b
+    let relative_a = a - b;
+
+    // in order for a rock to hit both a and b hailstones
+    // it must be moving on a plane formed by the following three points:
+    // the position of hailstone b (origin point),
+    // relative position of hailstone a,
+    // and the relative position of hailstone a after one step
+
+    // normal of a plane is perpendicular to any two vectors between those points
+    let normal = relative_a.point.cross(&relative_a.vector);
+
+    // convert our coordinates from relative to absolute
+    // plane will still have the correct orientation but will probably not go through the origin anymore
+    // we can adjust back to this relative origin via a constant on the relative plane
+
+    // calculate the velocity of a rock that starts at b and hits a after one step
+    let rock_velocity = relative_a.point + a.vector;
+    let constant = rock_velocity.dot(&normal);
+
+    let mut plane = Plane { normal, constant };
+
+    // try to reduce the numbers down to keep them manageable
+    plane.reduce();
+
+    plane
+}
+
+fn intersect(hailstone1: &Hailstone, hailstone2: &Hailstone) -> Option<(f64, f64)> {
+    let m1 = hailstone1.vector.1 / hailstone1.vector.0;
+    let m2 = hailstone2.vector.1 / hailstone2.vector.0;
+    if (m2 - m1).abs() < f64::EPSILON {
+        // equal slopes means parallel lines
+        return None;
+    }
+    let x = (m1 * hailstone1.point.0 - m2 * hailstone2.point.0 + hailstone2.point.1
+        - hailstone1.point.1)
+        / (m1 - m2);
+    let y = (m1 * m2 * (hailstone2.point.0 - hailstone1.point.0) + m2 * hailstone1.point.1
+        - m1 * hailstone2.point.1)
+        / (m2 - m1);
+
+    // check if happened in the past
+    if (x - hailstone1.point.0) / hailstone1.vector.0 > 0.0
+        && (x - hailstone2.point.0) / hailstone2.vector.0 > 0.0
+    {
+        return Some((x, y));
+    }
+
+    None
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    Day24::parse(input).and_then(|hailstorm| Day24::part_one(&hailstorm))
+}
+
+pub fn part_two(input: &str) -> Result<Option<i128>> {
+    Day24::parse(input).and_then(|hailstorm| Day24::part_two(&hailstorm))
+}
+
+/// Number of pairs of hailstones whose paths cross inside the test area.
+fn count_intersections(hailstones: &[Hailstone], area: &RangeInclusive<f64>) -> u32 {
+    let mut count = 0;
+    for i in 0..hailstones.len() {
+        for j in i + 1..hailstones.len() {
+            if let Some((x, y)) = intersect(&hailstones[i], &hailstones[j]) {
+                if area.contains(&x) && area.contains(&y) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Sum of the coordinates of the position to throw the rock from, so that it hits every hailstone.
+fn throw_rock(hailstones: &[Line]) -> Result<i128> {
+    let [first, second, third, ..] = hailstones else {
+        return Err(Error::solver("at least 3 hailstones are needed"));
+    };
+
+    // Compute the rock velocity
+
+    // construct three planes of velocity from our first three independent hailstones
+    let a = find_rock_velocities(first, second);
+    let b = find_rock_velocities(first, third);
+    let c = find_rock_velocities(second, third);
+
+    // intersection of all three planes will be the line common through the given planes, thus our desired velocity
+    let rock_velocity = Plane::intersect(&a, &b, &c).ok_or_else(|| {
+        Error::solver("the first three hailstones do not determine the velocity of the rock")
+    })?;
+
+    // now work backwards to find the starting position
+    // treat the rock as stationary and the velocity of a hailstone line is '-rock_velocity'
+    // then find where our adjusted lines intersect to find our starting point (we only need two for a single point)
+    let rock = Line {
+        point: Vector3::zeros(),
+        vector: rock_velocity,
+    };
+
+    let hailstone_a = first - &rock;
+    let hailstone_b = second - &rock;
+
+    // line intersection
+    // find out how far along hailstone_a we hit hailstone_b by finding the difference of the points
+    // and dividing how quickly hailstone_a approaches hailstone_b. We nullify the impact of
+    // hailstone_b's velocity by taking the cross product of both sides
+
+    let top = (hailstone_b.point - hailstone_a.point).cross(&hailstone_b.vector);
+    let bottom = hailstone_a.vector.cross(&hailstone_b.vector);
+
+    // vectors are parallel so it doesn't matter which non-zero component we take for division
+    let Some((top, bottom)) = top
+        .iter()
+        .zip(bottom.iter())
+        .find(|(_, &bottom)| bottom != 0)
+    else {
+        return Err(Error::solver(
+            "the first two hailstones never meet the rock at different places",
+        ));
+    };
+    let t0 = top / bottom;
+
+    // Calculate the rock's position at t0
+
+    let rock_position = hailstone_a.point + hailstone_a.vector * t0;
+
+    Ok(rock_position.x + rock_position.y + rock_position.z)
+}
+
+pub struct Hailstorm {
+    /// Hailstones in floating point for the paths of part one, and exactly for part two.
+    hailstones: Vec<Hailstone>,
+    lines: Vec<Line>,
+    /// Test area of part one.
+    area: RangeInclusive<f64>,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+
+    type Input<'a> = Hailstorm;
+    type PartOne = u32;
+    type PartTwo = i128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Input<'a>> {
+        let lines: Vec<Line> = input
+            .trim_end()
+            .lines()
+            .map(|line| Line::new(input, line))
+            .collect::<Result<_>>()?;
+        Ok(Hailstorm {
+            hailstones: lines.iter().map(Hailstone::from).collect(),
+            lines,
+            area: parameters.get_or("min", MIN)?..=parameters.get_or("max", MAX)?,
+        })
+    }
+
+    fn part_one(hailstorm: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(Some(count_intersections(
+            &hailstorm.hailstones,
+            &hailstorm.area,
+        )))
+    }
+
+    fn part_two(hailstorm: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        throw_rock(&hailstorm.lines).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::template::read_file("examples", 2023, 24);
+        let parameters = Parameters::new().with("min", 7).with("max", 27);
+        let result = Day24::parse_with(&input, &parameters)
+            .and_then(|hailstorm| Day24::part_one(&hailstorm));
+        assert_eq!(result, Ok(Some(2)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 24));
+        assert_eq!(result, Ok(Some(47)));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::template::{
+    error::{split_once_at, Error, Result},
+    Solution,
+};
+use crate::utils::graph::{Graph, Interner};
+
+fn construct_graph(input: &str) -> Result<Graph> {
+    let mut components = Interner::new();
+    let mut graph = Graph::default();
+
+    for line in input.trim_end().lines() {
+        let (key, values) = split_once_at(input, line, ": ")?;
+        let key = components.intern(key);
+
+        // connections are bidirectional and not duplicated in input
+        for connection in values.split_whitespace() {
+            graph.add_undirected_edge(key, components.intern(connection), 1);
+        }
+    }
+
+    Ok(graph)
+}
+
+pub fn part_one(input: &str) -> Result<Option<usize>> {
+    Day25::parse(input).and_then(|input| Day25::part_one(&input))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    Day25::parse(input).and_then(|input| Day25::part_two(&input))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Snowverload";
+
+    type Input<'a> = Graph;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        construct_graph(input)
+    }
+
+    fn part_one(graph: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        let Some((wires, group)) = graph.min_cut() else {
+            return Ok(None);
+        };
+
+        if wires != 3 {
+            return Err(Error::solver(format!(
+                "expected to cut 3 wires, the minimum cut has {wires}"
+            )));
+        }
+
+        Ok(Some(group.len() * (graph.len() - group.len())))
+    }
+
+    fn part_two(_graph: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Ok(Some(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", 2023, 25));
+        assert_eq!(result, Ok(Some(54)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", 2023, 25));
+        assert_eq!(result, Ok(Some(1)));
+    }
+}
@@ -0,0 +1,161 @@
+/// Store of accepted answers, used to check that solutions keep producing them.
+use std::{fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers: {e}"),
+            Error::IO(e) => write!(f, "could not access answers: {e}"),
+        }
+    }
+}
+
+/// Accepted answers of a day, stored in `data/YYYY/answers/NN.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+/// Outcome of checking a part's answer against the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    /// The part produced the recorded answer.
+    Pass,
+    /// The part produced an answer that differs from the recorded one.
+    Mismatch { expected: String, actual: String },
+    /// The part did not produce an answer, but one was recorded.
+    Fail { expected: String },
+    /// There is no recorded answer for this part.
+    Unknown,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.into()),
+            2 => self.part_two = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    #[must_use]
+    pub fn verify(&self, part: u8, actual: Option<&str>) -> Verification {
+        match (self.get(part), actual) {
+            (None, _) => Verification::Unknown,
+            (Some(expected), None) => Verification::Fail {
+                expected: expected.into(),
+            },
+            (Some(expected), Some(actual)) if expected == actual => Verification::Pass,
+            (Some(expected), Some(actual)) => Verification::Mismatch {
+                expected: expected.into(),
+                actual: actual.into(),
+            },
+        }
+    }
+
+    fn from_toml(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    fn to_toml(&self) -> String {
+        toml::to_string(self).expect("answers are always serializable")
+    }
+}
+
+fn get_path(year: u16, day: u8) -> PathBuf {
+    get_data_path("answers", year, day, "toml")
+}
+
+/// Load the recorded answers of a day. Days without an answers file have no recorded answers.
+pub fn load(year: u16, day: u8) -> Result<Answers, Error> {
+    match fs::read_to_string(get_path(year, day)) {
+        Ok(s) => Answers::from_toml(&s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Record an accepted answer, keeping the answer of the other part.
+pub fn record(year: u16, day: u8, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = load(year, day)?;
+    answers.set(part, answer);
+
+    let path = get_path(year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, answers.to_toml())?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verification};
+
+    #[test]
+    fn roundtrips_toml() {
+        let mut answers = Answers::default();
+        answers.set(1, "42");
+        assert_eq!(answers.to_toml(), "part_one = \"42\"\n");
+
+        answers.set(2, "1337");
+        assert_eq!(Answers::from_toml(&answers.to_toml()).unwrap(), answers);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(Answers::from_toml("part_one = 42").is_err());
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers {
+            part_one: Some("42".into()),
+            part_two: None,
+        };
+
+        assert_eq!(answers.verify(1, Some("42")), Verification::Pass);
+        assert_eq!(
+            answers.verify(1, Some("43")),
+            Verification::Mismatch {
+                expected: "42".into(),
+                actual: "43".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(1, None),
+            Verification::Fail {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(answers.verify(2, Some("1")), Verification::Unknown);
+    }
+}
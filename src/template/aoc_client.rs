@@ -0,0 +1,367 @@
+/// Minimal client for adventofcode.com, used to download inputs and puzzles and to submit answers.
+use std::{fmt::Display, fs, io, path::Path, time::Duration};
+
+use crate::template::get_data_path;
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16, String),
+    Transport(String),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session token found. Set AOC_SESSION or write it to \"~/.adventofcode.session\"."
+            ),
+            AocClientError::BadStatus(status, url) => {
+                write!(f, "request to {url} failed with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IoError(e) => write!(f, "could not write output files: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => {
+                AocClientError::BadStatus(status, response.get_url().to_string())
+            }
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.trim().into(),
+            year,
+        }
+    }
+
+    /// Build a client for `year` from the environment:
+    ///  - `AOC_SESSION` or the file `~/.adventofcode.session` for the session token.
+    ///  - `AOC_BASE_URL` to point the client at a different server, e.g. a local mock.
+    pub fn from_env(year: u16) -> Result<Self, AocClientError> {
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    /// Fetch the puzzle input of a day.
+    pub fn input(&self, day: u8) -> Result<String, AocClientError> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        self.get(&url)
+    }
+
+    /// Fetch the description of a day's puzzle, converted to markdown.
+    pub fn puzzle(&self, day: u8) -> Result<String, AocClientError> {
+        let url = format!("{}/{}/day/{day}", self.base_url, self.year);
+        let html = self.get(&url)?;
+        Ok(html_to_markdown(&extract_articles(&html)))
+    }
+
+    /// Submit an answer and return the server's response message as text.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+        Ok(html_to_markdown(&extract_articles(&html)))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(url)
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+pub fn download(year: u16, day: u8) -> Result<(), AocClientError> {
+    let client = AocClient::from_env(year)?;
+    let input_path = get_data_path("inputs", year, day, "txt");
+    let puzzle_path = get_data_path("puzzles", year, day, "md");
+
+    let input = client.input(day)?;
+    let puzzle = client.puzzle(day)?;
+
+    write_file(&input_path, &input)?;
+    write_file(&puzzle_path, &puzzle)?;
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+pub fn read(year: u16, day: u8) -> Result<(), AocClientError> {
+    let client = AocClient::from_env(year)?;
+    let puzzle = client.puzzle(day)?;
+
+    write_file(&get_data_path("puzzles", year, day, "md"), &puzzle)?;
+
+    println!("{puzzle}");
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), io::Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+fn get_session() -> Option<String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()?;
+
+    fs::read_to_string(format!("{home}/.adventofcode.session"))
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+}
+
+/// Extract the contents of all `<article>` elements, which hold puzzle descriptions and submission responses.
+fn extract_articles(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let after_tag = &rest[start..];
+        let Some(content_start) = after_tag.find('>') else {
+            break;
+        };
+        let Some(end) = after_tag.find("</article>") else {
+            break;
+        };
+        articles.push(&after_tag[content_start + 1..end]);
+        rest = &after_tag[end + "</article>".len()..];
+    }
+
+    articles.join("\n")
+}
+
+/// Convert the small subset of HTML used on adventofcode.com to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+        let is_closing = tag.starts_with('/');
+
+        match (name, is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            // emphasis in examples only highlights parts of them, and would change the example in markdown
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    out.push_str(&decode_entities(rest));
+    out.trim().to_string() + "\n"
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::{html_to_markdown, AocClient};
+
+    /// Serve a single request on a local port and return its base url.
+    /// The handle resolves to the raw request that was received.
+    fn mock_server(status: u16, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length: ") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body_buf = vec![0; content_length];
+            reader.read_exact(&mut body_buf).unwrap();
+            request.push_str(&String::from_utf8(body_buf).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (base_url, server) = mock_server(200, "1721\n979\n");
+        let client = AocClient::new(&base_url, "secret", 2023);
+
+        assert_eq!(client.input(1).unwrap(), "1721\n979\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn fetches_puzzle() {
+        let (base_url, server) = mock_server(
+            200,
+            "<html><main><article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2><p>Some <em>text</em> &amp; <code>code</code>.</p><pre><code>1abc2\n</code></pre></article></main></html>",
+        );
+        let client = AocClient::new(&base_url, "secret", 2023);
+
+        assert_eq!(
+            client.puzzle(1).unwrap(),
+            "## --- Day 1: Trebuchet?! ---\n\nSome *text* & `code`.\n\n```\n1abc2\n```\n"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1 HTTP/1.1"));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, server) = mock_server(
+            200,
+            "<article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article>",
+        );
+        let client = AocClient::new(&base_url, "secret", 2023);
+
+        let message = client.submit(1, 2, "281").unwrap();
+        assert!(message.starts_with("That's the right answer!"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=281"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, server) = mock_server(400, "");
+        let client = AocClient::new(&base_url, "expired", 2023);
+
+        assert!(client.input(1).is_err());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn converts_lists() {
+        assert_eq!(
+            html_to_markdown("<ul><li>one</li><li>two</li></ul>"),
+            "- one\n- two\n"
+        );
+    }
+
+    #[test]
+    fn keeps_examples_verbatim() {
+        assert_eq!(
+            html_to_markdown(
+                "<pre><code>467..<em>*</em>..\n</code></pre><p><code><em>4361</em></code></p>"
+            ),
+            "```\n467..*..\n```\n\n`*4361*`\n"
+        );
+    }
+}
@@ -0,0 +1,401 @@
+/// Persists benchmark results and compares later runs against them to detect regressions.
+use std::{
+    fs, io,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{runner::DayResult, stats::BenchStats, ANSI_BOLD, ANSI_RESET};
+
+static BASELINE_PATH: &str = "data/baseline.json";
+
+/// Part number used for entries of the parse stage.
+pub const PARSE_STAGE: u8 = 0;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(serde_json::Error),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parser(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse baseline: {e}"),
+            Error::IO(e) => write!(f, "could not access baseline: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BaselineOptions {
+    /// Overwrite existing baseline entries with the results of this run.
+    pub save: bool,
+    /// Slowdown in percent above which a part counts as regressed.
+    pub threshold: f64,
+}
+
+impl Default for BaselineOptions {
+    fn default() -> Self {
+        Self {
+            save: false,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Machine {
+    pub os: String,
+    pub arch: String,
+    pub cpus: usize,
+    pub hostname: Option<String>,
+}
+
+impl Machine {
+    #[must_use]
+    pub fn current() -> Self {
+        Self {
+            os: std::env::consts::OS.into(),
+            arch: std::env::consts::ARCH.into(),
+            cpus: std::thread::available_parallelism().map_or(1, std::num::NonZero::get),
+            hostname: std::env::var("HOSTNAME")
+                .or_else(|_| std::env::var("COMPUTERNAME"))
+                .ok()
+                .or_else(|| {
+                    fs::read_to_string("/etc/hostname")
+                        .ok()
+                        .map(|s| s.trim().to_string())
+                }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// Part of the day, or [`PARSE_STAGE`] for the shared parse stage.
+    pub part: u8,
+    /// Median execution time of the part.
+    pub nanos: u64,
+    pub samples: u64,
+    pub commit: Option<String>,
+    pub machine: Machine,
+    /// Seconds since the unix epoch.
+    pub recorded_at: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: Vec<Entry>,
+}
+
+/// Comparison of a part's current run against its baseline entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline_nanos: u64,
+    pub current_nanos: u64,
+    /// Relative change in percent, positive values are slowdowns.
+    pub change: f64,
+    pub is_regression: bool,
+    /// Whether the baseline was recorded on a different machine, which makes the comparison unreliable.
+    pub is_foreign: bool,
+}
+
+impl Baseline {
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day && entry.part == part)
+    }
+
+    /// Compare a run against its baseline entry, if there is one.
+    #[must_use]
+    pub fn compare(&self, entry: &Entry, threshold: f64) -> Option<Comparison> {
+        let baseline = self.get(entry.year, entry.day, entry.part)?;
+        let change =
+            (entry.nanos as f64 - baseline.nanos as f64) / (baseline.nanos.max(1) as f64) * 100.0;
+
+        Some(Comparison {
+            year: entry.year,
+            day: entry.day,
+            part: entry.part,
+            baseline_nanos: baseline.nanos,
+            current_nanos: entry.nanos,
+            change,
+            is_regression: change > threshold,
+            is_foreign: baseline.machine != entry.machine,
+        })
+    }
+
+    /// Record an entry. Existing entries for the same day and part are only replaced if `overwrite` is set.
+    pub fn record(&mut self, entry: Entry, overwrite: bool) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| e.year == entry.year && e.day == entry.day && e.part == entry.part)
+        {
+            Some(existing) => {
+                if overwrite {
+                    *existing = entry;
+                }
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.sort_by_key(|e| (e.year, e.day, e.part));
+            }
+        }
+    }
+}
+
+/// Build baseline entries for the parse stage and the solved parts of a timed run.
+/// The commit is filled in by [`update`], so that git only has to be queried once per run.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn entries_from_results(year: u16, day: u8, result: &DayResult) -> Vec<Entry> {
+    let machine = Machine::current();
+    let recorded_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let entry = |part: u8, stats: &BenchStats, samples: u128| Entry {
+        year,
+        day,
+        part,
+        nanos: stats.p50.as_nanos() as u64,
+        samples: samples as u64,
+        commit: None,
+        machine: machine.clone(),
+        recorded_at,
+    };
+
+    let parse = result
+        .parse
+        .stats
+        .as_ref()
+        .map(|stats| entry(PARSE_STAGE, stats, result.parse.samples));
+
+    let parts = result
+        .parts
+        .iter()
+        .filter(|part| part.answer.is_some())
+        .filter_map(|part| Some(entry(part.part, part.stats.as_ref()?, part.samples)));
+
+    parse.into_iter().chain(parts).collect()
+}
+
+/// Short hash of the checked out commit, if this is a git repository.
+#[must_use]
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn load() -> Result<Baseline, Error> {
+    match fs::read_to_string(BASELINE_PATH) {
+        Ok(s) => Ok(serde_json::from_str(&s)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn save(baseline: &Baseline) -> Result<(), Error> {
+    fs::write(
+        BASELINE_PATH,
+        serde_json::to_string_pretty(baseline)? + "\n",
+    )?;
+    Ok(())
+}
+
+/// Compare the entries of a timed run against the baseline file and record new entries.
+/// Returns the comparisons for all parts that already had a baseline entry.
+fn update(entries: Vec<Entry>, options: BaselineOptions) -> Result<Vec<Comparison>, Error> {
+    let mut baseline = load()?;
+    let commit = current_commit();
+    let mut comparisons = vec![];
+
+    for mut entry in entries {
+        entry.commit.clone_from(&commit);
+        if let Some(comparison) = baseline.compare(&entry, options.threshold) {
+            comparisons.push(comparison);
+        }
+        baseline.record(entry, options.save);
+    }
+
+    save(&baseline)?;
+    Ok(comparisons)
+}
+
+/// Print a summary of the comparisons. Returns `true` if any part regressed.
+fn report(comparisons: &[Comparison], threshold: f64) -> bool {
+    let regressions: Vec<_> = comparisons.iter().filter(|c| c.is_regression).collect();
+
+    if comparisons.iter().any(|c| c.is_foreign) {
+        eprintln!("Warning: some baseline entries were recorded on a different machine.");
+    }
+
+    for c in &regressions {
+        let stage = match c.part {
+            PARSE_STAGE => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+        eprintln!(
+            "{ANSI_BOLD}Regression:{ANSI_RESET} {} Day {} {stage}: {:.1?} → {:.1?} ({:+.1}%, threshold {threshold}%)",
+            c.year,
+            c.day,
+            std::time::Duration::from_nanos(c.baseline_nanos),
+            std::time::Duration::from_nanos(c.current_nanos),
+            c.change,
+        );
+    }
+
+    !regressions.is_empty()
+}
+
+/// Update the baseline with the entries of a timed run and report regressions.
+/// Returns `true` if any part regressed, callers should then exit with a non-zero status.
+pub fn check(entries: Vec<Entry>, options: BaselineOptions) -> bool {
+    // debug timings are not comparable to optimized ones and would pollute the baseline.
+    if cfg!(debug_assertions) {
+        return false;
+    }
+
+    match update(entries, options) {
+        Ok(comparisons) => report(&comparisons, options.threshold),
+        Err(e) => {
+            eprintln!("Failed to update benchmark baseline: {e}");
+            false
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Baseline, Entry, Machine};
+
+    fn machine(hostname: &str) -> Machine {
+        Machine {
+            os: "linux".into(),
+            arch: "x86_64".into(),
+            cpus: 8,
+            hostname: Some(hostname.into()),
+        }
+    }
+
+    fn entry(day: u8, part: u8, nanos: u64) -> Entry {
+        Entry {
+            year: 2023,
+            day,
+            part,
+            nanos,
+            samples: 100,
+            commit: Some("abc1234".into()),
+            machine: machine("elf"),
+            recorded_at: 0,
+        }
+    }
+
+    #[test]
+    fn records_missing_entries() {
+        let mut baseline = Baseline::default();
+        baseline.record(entry(2, 1, 100), false);
+        baseline.record(entry(1, 2, 100), false);
+        baseline.record(entry(1, 1, 100), false);
+
+        let keys: Vec<_> = baseline.entries.iter().map(|e| (e.day, e.part)).collect();
+        assert_eq!(keys, vec![(1, 1), (1, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn separates_years() {
+        let mut baseline = Baseline::default();
+        baseline.record(entry(1, 1, 100), false);
+
+        let mut other_year = entry(1, 1, 200);
+        other_year.year = 2022;
+        assert!(baseline.compare(&other_year, 10.0).is_none());
+
+        baseline.record(other_year, false);
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(baseline.entries[0].year, 2022);
+    }
+
+    #[test]
+    fn keeps_existing_entries_unless_overwritten() {
+        let mut baseline = Baseline::default();
+        baseline.record(entry(1, 1, 100), false);
+
+        baseline.record(entry(1, 1, 200), false);
+        assert_eq!(baseline.get(2023, 1, 1).unwrap().nanos, 100);
+
+        baseline.record(entry(1, 1, 200), true);
+        assert_eq!(baseline.get(2023, 1, 1).unwrap().nanos, 200);
+        assert_eq!(baseline.entries.len(), 1);
+    }
+
+    #[test]
+    fn flags_regressions_above_threshold() {
+        let mut baseline = Baseline::default();
+        baseline.record(entry(23, 2, 1000), false);
+
+        let within = baseline.compare(&entry(23, 2, 1100), 10.0).unwrap();
+        assert!(!within.is_regression);
+
+        let regressed = baseline.compare(&entry(23, 2, 1101), 10.0).unwrap();
+        assert!(regressed.is_regression);
+        assert!((regressed.change - 10.1).abs() < 1e-9);
+
+        let faster = baseline.compare(&entry(23, 2, 500), 10.0).unwrap();
+        assert!(!faster.is_regression);
+        assert!((faster.change + 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn skips_parts_without_baseline() {
+        let baseline = Baseline::default();
+        assert!(baseline.compare(&entry(1, 1, 100), 10.0).is_none());
+    }
+
+    #[test]
+    fn detects_foreign_machine() {
+        let mut baseline = Baseline::default();
+        baseline.record(entry(1, 1, 100), false);
+
+        let mut other = entry(1, 1, 100);
+        other.machine = machine("reindeer");
+        assert!(baseline.compare(&other, 10.0).unwrap().is_foreign);
+        assert!(
+            !baseline
+                .compare(&entry(1, 1, 100), 10.0)
+                .unwrap()
+                .is_foreign
+        );
+    }
+}
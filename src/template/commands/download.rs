@@ -1,14 +1,10 @@
-use crate::template::aoc_cli;
 use std::process;
 
-pub fn handle(day: u8) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+use crate::template::aoc_client;
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(year: u16, day: u8) {
+    if let Err(e) = aoc_client::download(year, day) {
+        eprintln!("failed to download day {day} of {year}: {e}");
         process::exit(1);
     };
 }
@@ -0,0 +1,113 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{
+    examples::{self, Extracted},
+    get_data_path, get_data_path_part,
+};
+
+pub fn handle(year: u16, day: u8) {
+    let puzzle_path = get_data_path("puzzles", year, day, "md");
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{}\", download the puzzle with `cargo download {day}` first.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    };
+
+    let (part_one, part_two) = examples::extract(&puzzle);
+    let example_path = get_data_path("examples", year, day, "txt");
+    let answers_path = get_data_path("examples", year, day, "toml");
+
+    report_candidates(1, &part_one);
+    if let Some(example) = part_one.example() {
+        write_example(&example_path, example);
+    }
+    if let Some(answer) = &part_one.answer {
+        set_answer(&answers_path, "part_one", answer);
+    }
+
+    match part_two {
+        Some(part_two) => {
+            report_candidates(2, &part_two);
+            // part two reuses the example of part one unless it comes with its own
+            let answers_path = match part_two.example() {
+                Some(example) => {
+                    write_example(
+                        &get_data_path_part("examples", year, day, "2", "txt"),
+                        example,
+                    );
+                    get_data_path_part("examples", year, day, "2", "toml")
+                }
+                None => answers_path,
+            };
+            if let Some(answer) = &part_two.answer {
+                set_answer(&answers_path, "part_two", answer);
+            }
+        }
+        None => {
+            println!("Part two is not unlocked yet, run `cargo download {day}` again once it is.")
+        }
+    }
+
+    println!("---");
+    println!(
+        "🎄 Check the extracted examples and answers against the puzzle, then run `cargo test day{day:02}` to check the solution against them."
+    );
+}
+
+fn report_candidates(part: u8, extracted: &Extracted) {
+    match extracted.blocks.len() {
+        0 => println!("Part {part} has no code blocks"),
+        1 => {}
+        n => println!("Part {part} has {n} code blocks, using the first one as its example"),
+    }
+    if extracted.answer.is_none() {
+        println!("Part {part} has no emphasized answer");
+    }
+}
+
+/// Write an example, unless its file has contents already.
+fn write_example(path: &Path, example: &str) {
+    if fs::read_to_string(path).is_ok_and(|contents| !contents.trim().is_empty()) {
+        println!("Kept existing example file \"{}\"", path.display());
+        return;
+    }
+
+    match fs::write(path, example) {
+        Ok(()) => println!("Wrote example file \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write example file \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
+
+/// Record the expected answer `key` of an example, unless it has one already. Other entries, like parameters, are kept.
+fn set_answer(path: &Path, key: &str, answer: &str) {
+    let mut table: toml::Table = match fs::read_to_string(path) {
+        Ok(contents) => match toml::from_str(&contents) {
+            Ok(table) => table,
+            Err(e) => {
+                eprintln!("Failed to parse \"{}\": {e}", path.display());
+                process::exit(1);
+            }
+        },
+        Err(_) => toml::Table::new(),
+    };
+
+    if let Some(existing) = table.get(key) {
+        println!("Kept existing {key} = {existing} in \"{}\"", path.display());
+        return;
+    }
+
+    table.insert(key.to_string(), answer.into());
+    let contents = toml::to_string(&table).expect("tables are always serializable");
+    match fs::write(path, contents) {
+        Ok(()) => println!("Wrote {key} = \"{answer}\" to \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
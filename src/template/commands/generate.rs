@@ -0,0 +1,42 @@
+use std::process;
+
+use crate::solutions;
+use crate::template::report::{OutputFormat, Reporter};
+use crate::template::runner::RunOptions;
+
+/// Print a generated input, or run the day on it with `time`, e.g. to see how a solution scales with `size`.
+pub fn handle(year: u16, day: u8, seed: u64, size: usize, time: bool, format: OutputFormat) {
+    let Some(solution) = solutions::get(year, day) else {
+        eprintln!("Day {day} of {year} has not been scaffolded yet. Try running \"cargo scaffold {day} --year {year}\".");
+        process::exit(1);
+    };
+
+    let Some(input) = solution.generate(seed, size) else {
+        eprintln!(
+            "Day {day} of {year} has no input generator, implement `Solution::generate` for it."
+        );
+        process::exit(1);
+    };
+
+    if !time {
+        print!("{input}");
+        return;
+    }
+
+    let results = solution.run(
+        &input,
+        RunOptions {
+            is_timed: true,
+            format,
+            is_quiet: false,
+        },
+    );
+
+    let mut reporter = Reporter::new(format);
+    reporter.add(year, day, &results);
+    reporter.finish();
+
+    if results.errors().next().is_some() {
+        process::exit(1);
+    }
+}
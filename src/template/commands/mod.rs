@@ -1,5 +1,10 @@
 pub mod all;
 pub mod download;
+pub mod examples;
+pub mod generate;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod trace;
+pub mod verify;
+pub mod visualize;
@@ -1,51 +1,170 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r#"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const MODULE_TEMPLATE: &str = r#"use crate::template::{error::Result, Solution};
+
+pub fn part_one(input: &str) -> Result<Option<u32>> {
+    DayDAY_PADDED::parse(input).and_then(|input| DayDAY_PADDED::part_one(&input))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+pub fn part_two(input: &str) -> Result<Option<u32>> {
+    DayDAY_PADDED::parse(input).and_then(|input| DayDAY_PADDED::part_two(&input))
 }
 
-advent_of_code::main!(DAY);
+pub struct DayDAY_PADDED;
+
+impl Solution for DayDAY_PADDED {
+    const YEAR: u16 = YEAR_NUMBER;
+    const DAY: u8 = DAY_NUMBER;
+    const TITLE: &'static str = "";
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(_input: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+        Ok(None)
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+        Ok(None)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    fn test_examples() {
+        crate::template::examples::check::<DayDAY_PADDED>();
     }
 }
 "#;
 
+const YEAR_MODULE_TEMPLATE: &str = r#"/// Registry of all solved days of YEAR_NUMBER.
+/// New days are added here by `cargo scaffold`, which appends to the module list and `DAYS`.
+use crate::template::Day;
+
+pub static DAYS: &[&dyn Day] = &[];
+"#;
+
+const REGISTRY_PATH: &str = "src/solutions/mod.rs";
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Add a `pub mod` declaration for `module` and an `entry` to the static list `name` of a registry file.
+fn register_module(
+    path: &str,
+    name: &str,
+    module: &str,
+    entry: &str,
+) -> Result<(), std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+    let registry = add_to_registry(&registry, name, module, entry).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("could not locate `{name}` in registry"),
+        )
+    })?;
+    fs::write(path, registry)
+}
+
+fn add_to_registry(registry: &str, name: &str, module: &str, entry: &str) -> Option<String> {
+    let list_start = registry.find(&format!("pub static {name}"))?;
+    let items_start = list_start + registry[list_start..].find("= &[")? + "= &[".len();
+    let items_end = items_start + registry[items_start..].find("];")?;
+
+    let mut entries: Vec<&str> = registry[items_start..items_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect();
+    entries.push(entry);
+
+    // module declarations go right before the registry, after any existing ones.
+    let head = registry[..list_start].trim_end();
+    let separator = if head.lines().last()?.starts_with("pub mod") {
+        "\n"
+    } else {
+        "\n\n"
+    };
+
+    let mut updated = String::with_capacity(registry.len() + module.len() + entry.len() + 16);
+    updated.push_str(head);
+    updated.push_str(separator);
+    updated.push_str(&format!("pub mod {module};\n\n"));
+    updated.push_str(&registry[list_start..items_start]);
+    updated.push('\n');
+    for entry in entries {
+        updated.push_str(&format!("    {entry},\n"));
+    }
+    updated.push_str(&registry[items_end..]);
+    Some(updated)
 }
 
-pub fn handle(day: u8) {
+/// Create the module of a year and register it, unless it already exists.
+fn create_year_module(year: u16) -> Result<bool, std::io::Error> {
+    let year_dir = format!("src/solutions/year{year}");
+    if Path::new(&year_dir).exists() {
+        return Ok(false);
+    }
+
+    fs::create_dir_all(&year_dir)?;
+    fs::write(
+        format!("{year_dir}/mod.rs"),
+        YEAR_MODULE_TEMPLATE.replace("YEAR_NUMBER", &year.to_string()),
+    )?;
+    register_module(
+        REGISTRY_PATH,
+        "YEARS",
+        &format!("year{year}"),
+        &format!("year{year}::DAYS"),
+    )?;
+    Ok(true)
+}
+
+pub fn handle(year: u16, day: u8) {
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("data/inputs/{day_padded}.txt");
-    let example_path = format!("data/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    let input_path = format!("data/{year}/inputs/{day_padded}.txt");
+    let example_path = format!("data/{year}/examples/{day_padded}.txt");
+    let example_answers_path = format!("data/{year}/examples/{day_padded}.toml");
+    let module_path = format!("src/solutions/year{year}/day{day_padded}.rs");
+    let year_registry_path = format!("src/solutions/year{year}/mod.rs");
+
+    match create_year_module(year) {
+        Ok(true) => {
+            println!("Created module for year {year} in \"{year_registry_path}\"");
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to create module for year {year}: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -55,7 +174,12 @@ pub fn handle(day: u8) {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    let module = MODULE_TEMPLATE
+        .replace("DAY_PADDED", &day_padded)
+        .replace("DAY_NUMBER", &day.to_string())
+        .replace("YEAR_NUMBER", &year.to_string());
+
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -65,6 +189,21 @@ pub fn handle(day: u8) {
         }
     }
 
+    match register_module(
+        &year_registry_path,
+        "DAYS",
+        &format!("day{day_padded}"),
+        &format!("&day{day_padded}::Day{day_padded}"),
+    ) {
+        Ok(()) => {
+            println!("Registered module in \"{year_registry_path}\"");
+        }
+        Err(e) => {
+            eprintln!("Failed to register module: {e}");
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -75,9 +214,20 @@ pub fn handle(day: u8) {
         }
     }
 
-    match create_file(&example_path) {
+    create_example_files(&example_path, &example_answers_path);
+
+    println!("---");
+    println!(
+        "🎄 Type `cargo solve {} --year {year}` to run your solution.",
+        &day_padded
+    );
+}
+
+/// Create an empty example and an empty file for its expected answers, keeping existing contents.
+fn create_example_files(example_path: &str, answers_path: &str) {
+    match create_file(example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{example_path}\"");
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -85,9 +235,138 @@ pub fn handle(day: u8) {
         }
     }
 
+    match create_file(answers_path) {
+        Ok(_) => {
+            println!("Created empty example answers file \"{answers_path}\"");
+        }
+        Err(e) => {
+            eprintln!("Failed to create example answers file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Add another example to an existing day, e.g. `NN-2.txt` for a separate part two example.
+pub fn handle_example(year: u16, day: u8, name: &str) {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        eprintln!("Example names may only contain letters and digits, got \"{name}\"");
+        process::exit(1);
+    }
+
+    let day_padded = format!("{day:02}");
+    create_example_files(
+        &format!("data/{year}/examples/{day_padded}-{name}.txt"),
+        &format!("data/{year}/examples/{day_padded}-{name}.toml"),
+    );
+
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Put the expected answers into the answers file, the `test_examples` test of day {day_padded} checks them."
     );
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::add_to_registry;
+
+    #[test]
+    fn registers_module() {
+        let registry = [
+            "use crate::template::Day;",
+            "",
+            "pub mod day01;",
+            "",
+            "pub static DAYS: &[&dyn Day] = &[",
+            "    &day01::Day01,",
+            "];",
+            "",
+        ]
+        .join("\n");
+
+        let expected = [
+            "use crate::template::Day;",
+            "",
+            "pub mod day01;",
+            "pub mod day02;",
+            "",
+            "pub static DAYS: &[&dyn Day] = &[",
+            "    &day01::Day01,",
+            "    &day02::Day02,",
+            "];",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            add_to_registry(&registry, "DAYS", "day02", "&day02::Day02").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn registers_module_in_empty_registry() {
+        let registry = [
+            "use crate::template::Day;",
+            "",
+            "pub static DAYS: &[&dyn Day] = &[];",
+            "",
+        ]
+        .join("\n");
+
+        let expected = [
+            "use crate::template::Day;",
+            "",
+            "pub mod day01;",
+            "",
+            "pub static DAYS: &[&dyn Day] = &[",
+            "    &day01::Day01,",
+            "];",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            add_to_registry(&registry, "DAYS", "day01", "&day01::Day01").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn registers_year() {
+        let registry = [
+            "use crate::template::Day;",
+            "",
+            "pub mod year2023;",
+            "",
+            "pub static YEARS: &[&[&dyn Day]] = &[year2023::DAYS];",
+            "",
+            "pub fn all() {}",
+        ]
+        .join("\n");
+
+        let expected = [
+            "use crate::template::Day;",
+            "",
+            "pub mod year2023;",
+            "pub mod year2024;",
+            "",
+            "pub static YEARS: &[&[&dyn Day]] = &[",
+            "    year2023::DAYS,",
+            "    year2024::DAYS,",
+            "];",
+            "",
+            "pub fn all() {}",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            add_to_registry(&registry, "YEARS", "year2024", "year2024::DAYS").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn fails_without_registry() {
+        assert!(add_to_registry("pub mod day01;\n", "DAYS", "day02", "&day02::Day02").is_none());
+    }
+}
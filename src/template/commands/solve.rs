@@ -1,31 +1,132 @@
-use std::process::{Command, Stdio};
+use std::process;
 
-pub fn handle(day: u8, release: bool, time: bool, submit_part: Option<u8>) {
-    let day_padded = format!("{day:02}");
+use crate::solutions;
+use crate::template::baseline::{self, BaselineOptions};
+use crate::template::report::{OutputFormat, Reporter};
+use crate::template::runner::{self, RunOptions};
+use crate::template::{
+    answers,
+    aoc_client::AocClient,
+    read_file,
+    submissions::{self, Verdict},
+};
 
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day_padded];
+pub fn handle(
+    year: u16,
+    day: u8,
+    time: bool,
+    submit_part: Option<u8>,
+    format: OutputFormat,
+    baseline_options: BaselineOptions,
+) {
+    let Some(solution) = solutions::get(year, day) else {
+        eprintln!("Day {day} of {year} has not been scaffolded yet. Try running \"cargo scaffold {day} --year {year}\".");
+        process::exit(1);
+    };
 
-    if release {
-        cmd_args.push("--release".to_string());
+    let input = read_file("inputs", year, day);
+    let results = solution.run(
+        &input,
+        RunOptions {
+            is_timed: time,
+            format,
+            is_quiet: false,
+        },
+    );
+
+    let mut reporter = Reporter::new(format);
+    reporter.add(year, day, &results);
+    reporter.finish();
+
+    if results.errors().next().is_some() {
+        process::exit(1);
     }
 
-    cmd_args.push("--".to_string());
+    if time
+        && baseline::check(
+            baseline::entries_from_results(year, day, &results),
+            baseline_options,
+        )
+    {
+        process::exit(1);
+    }
 
     if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+        submit_result(&results.parts, year, day, submit_part);
     }
+}
+
+/// Try to submit one part of the solution if:
+///  1. the part produced a result.
+///  2. the answer was not ruled out by earlier submissions.
+///  3. a session token is configured.
+///
+/// The verdict is recorded to guard later submissions. Accepted answers are also recorded, so that `verify` can check them later on.
+fn submit_result(results: &[runner::PartResult], year: u16, day: u8, part: u8) {
+    let Some(answer) = results
+        .iter()
+        .find(|result| result.part == part)
+        .and_then(|result| result.answer.as_ref())
+    else {
+        eprintln!("Part {part} did not produce a result, nothing to submit.");
+        process::exit(1);
+    };
+
+    let mut history = match submissions::load(year, day) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    if time {
-        cmd_args.push("--time".to_string());
+    if let Err(rejection) = history.check(part, answer, submissions::now()) {
+        eprintln!("Not submitting \"{answer}\": {rejection}");
+        process::exit(1);
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    let client = match AocClient::from_env(year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to submit: {e}");
+            process::exit(1);
+        }
+    };
 
-    cmd.wait().unwrap();
+    // everything about the submission goes to stderr, so that `--format json` output stays parseable
+    eprintln!("Submitting result...");
+    let message = match client.submit(day, part, answer) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("failed to submit: {e}");
+            process::exit(1);
+        }
+    };
+    eprintln!("{message}");
+
+    let Some(verdict) = Verdict::from_message(&message) else {
+        return;
+    };
+
+    history.record_with_cooldown(
+        part,
+        answer,
+        verdict,
+        Verdict::cooldown(&message),
+        submissions::now(),
+    );
+    if let Err(e) = submissions::save(year, day, &history) {
+        eprintln!("failed to record submission: {e}");
+    }
+
+    if verdict == Verdict::Correct {
+        match answers::record(year, day, part, answer) {
+            Ok(()) => {
+                eprintln!(
+                    "🎄 Recorded answer for part {part} in \"data/{year}/answers/{day:02}.toml\"."
+                )
+            }
+            Err(e) => eprintln!("failed to record answer: {e}"),
+        }
+    }
 }
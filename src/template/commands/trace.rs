@@ -0,0 +1,112 @@
+use std::{fs, io::Write, process, thread, time::Duration};
+
+use crate::solutions;
+use crate::template::read_file;
+use crate::utils::trace::{self, Entry, Trace};
+
+/// Record the frames and events a part emits on the puzzle input, or on `input` if given, and replay them in the
+/// terminal `delay` milliseconds apart, or write them to `output` as an animated GIF or an asciicast.
+pub fn handle(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: Option<String>,
+    output: Option<String>,
+    delay: u64,
+) {
+    if !trace::ENABLED {
+        eprintln!("This build does not record traces, run it with \"cargo trace\" or build with \"--features trace\".");
+        process::exit(1);
+    }
+
+    let Some(solution) = solutions::get(year, day) else {
+        eprintln!("Day {day} of {year} has not been scaffolded yet. Try running \"cargo scaffold {day} --year {year}\".");
+        process::exit(1);
+    };
+
+    if !(1..=2).contains(&part) {
+        eprintln!("There is no part {part}, expected 1 or 2.");
+        process::exit(1);
+    }
+
+    // check the output before spending time on the solution
+    let extension = output.as_deref().map(|path| {
+        let extension = path
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some(extension @ ("gif" | "cast")) => extension.to_string(),
+            _ => {
+                eprintln!("Cannot tell the format of \"{path}\", expected a .gif or .cast file.");
+                process::exit(1);
+            }
+        }
+    });
+
+    let input = match input {
+        Some(path) => fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("Could not read \"{path}\": {e}");
+            process::exit(1);
+        }),
+        None => read_file("inputs", year, day),
+    };
+
+    let (answer, trace) = trace::record(|| solution.solve(&input, part));
+    let delay = Duration::from_millis(delay);
+
+    match (output, extension) {
+        (Some(path), Some(extension)) => {
+            let contents = match extension.as_str() {
+                "gif" => trace.to_gif(delay).unwrap_or_else(|e| {
+                    eprintln!("Failed to write \"{path}\": {e}");
+                    process::exit(1);
+                }),
+                _ => trace.to_asciicast(delay).into_bytes(),
+            };
+            match fs::write(&path, contents) {
+                Ok(()) => println!(
+                    "🎄 Wrote {} frames and {} events to \"{path}\".",
+                    trace.frames().count(),
+                    trace.entries.len() - trace.frames().count()
+                ),
+                Err(e) => {
+                    eprintln!("Failed to write \"{path}\": {e}");
+                    process::exit(1);
+                }
+            }
+        }
+        _ => replay(&trace, delay),
+    }
+
+    if trace.entries.is_empty() {
+        println!("Part {part} did not record anything, add `trace::frame` or `trace::event` calls to it.");
+    }
+    if trace.truncated {
+        println!("The trace stopped after {} entries.", trace::LIMIT);
+    }
+
+    match answer {
+        Ok(Some(answer)) => println!("Part {part}: {answer}"),
+        Ok(None) => println!("Part {part}: ✖"),
+        Err(e) => {
+            eprintln!("Part {part} failed: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Draw every frame over the last and print events below it, as fast as the asciicast would.
+fn replay(trace: &Trace, delay: Duration) {
+    let mut stdout = std::io::stdout().lock();
+    for entry in &trace.entries {
+        thread::sleep(entry.delay(delay));
+        let written = match entry {
+            Entry::Frame(canvas) => write!(stdout, "\x1b[H\x1b[2J{}", canvas.to_ansi()),
+            Entry::Event(text) => writeln!(stdout, "{text}"),
+        };
+        if let Err(e) = written.and_then(|()| stdout.flush()) {
+            eprintln!("Failed to replay the trace: {e}");
+            process::exit(1);
+        }
+    }
+}
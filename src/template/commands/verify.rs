@@ -0,0 +1,100 @@
+use std::process;
+
+use crate::solutions;
+use crate::template::{
+    answers::{self, Verification},
+    runner::RunOptions,
+    try_read_file, ANSI_BOLD, ANSI_RESET,
+};
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    mismatched: usize,
+    failed: usize,
+    unknown: usize,
+}
+
+/// Run every day of the given `years` against its input and compare the answers with the recorded ones.
+pub fn handle(years: &[u16]) {
+    let mut summary = Summary::default();
+
+    for (year, day) in years
+        .iter()
+        .flat_map(|&year| (1..=25).map(move |day| (year, day)))
+    {
+        let Some(solution) = solutions::get(year, day) else {
+            continue;
+        };
+
+        let Some(input) = try_read_file("inputs", year, day) else {
+            continue;
+        };
+
+        let answers = match answers::load(year, day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{year} Day {day}: {e}");
+                process::exit(1);
+            }
+        };
+
+        if years.len() > 1 {
+            println!("{ANSI_BOLD}{year} Day {day}{ANSI_RESET}");
+        } else {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        }
+
+        let results = solution.run(
+            &input,
+            RunOptions {
+                is_quiet: true,
+                ..RunOptions::default()
+            },
+        );
+
+        if let Some(e) = &results.parse.error {
+            println!("Parse: ✖ {e}");
+        }
+
+        for result in &results.parts {
+            let part = result.part;
+            if let Some(e) = &result.error {
+                summary.failed += 1;
+                println!("Part {part}: ✖ failed, {e}");
+                continue;
+            }
+
+            match answers.verify(part, result.answer.as_deref()) {
+                Verification::Pass => {
+                    summary.passed += 1;
+                    println!(
+                        "Part {part}: ✔ {}",
+                        result.answer.as_deref().unwrap_or_default()
+                    );
+                }
+                Verification::Mismatch { expected, actual } => {
+                    summary.mismatched += 1;
+                    println!("Part {part}: ✖ mismatch, expected {expected} but got {actual}");
+                }
+                Verification::Fail { expected } => {
+                    summary.failed += 1;
+                    println!("Part {part}: ✖ failed, expected {expected} but got no answer");
+                }
+                Verification::Unknown => {
+                    summary.unknown += 1;
+                    println!("Part {part}: - no recorded answer");
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{ANSI_BOLD}Verified:{ANSI_RESET} {} passed, {} mismatched, {} failed, {} without recorded answer.",
+        summary.passed, summary.mismatched, summary.failed, summary.unknown
+    );
+
+    if summary.mismatched > 0 || summary.failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,71 @@
+use std::{fs, io::Write, process};
+
+use crate::solutions;
+use crate::template::read_file;
+use crate::utils::visualize::Format;
+
+/// Draw what a part computes on the puzzle input, or on `input` if given, to the terminal or to `output`.
+pub fn handle(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: Option<String>,
+    output: Option<String>,
+    format: Option<Format>,
+) {
+    let Some(solution) = solutions::get(year, day) else {
+        eprintln!("Day {day} of {year} has not been scaffolded yet. Try running \"cargo scaffold {day} --year {year}\".");
+        process::exit(1);
+    };
+
+    if !(1..=2).contains(&part) {
+        eprintln!("There is no part {part}, expected 1 or 2.");
+        process::exit(1);
+    }
+
+    let input = match input {
+        Some(path) => fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("Could not read \"{path}\": {e}");
+            process::exit(1);
+        }),
+        None => read_file("inputs", year, day),
+    };
+
+    let canvas = match solution.visualize(&input, part) {
+        Ok(Some(canvas)) => canvas,
+        Ok(None) => {
+            eprintln!("Day {day} of {year} has no visualization of part {part}, implement `Solution::visualize` for it.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Could not visualize part {part}: {e}");
+            process::exit(1);
+        }
+    };
+
+    // files default to the format of their extension, the terminal to ANSI
+    let format = format
+        .or_else(|| output.as_deref().and_then(Format::from_extension))
+        .unwrap_or_default();
+    let rendered = canvas.render(format);
+
+    match output {
+        Some(path) => match fs::write(&path, rendered) {
+            Ok(()) => println!(
+                "🎄 Wrote the visualization of {}×{} cells as {format} to \"{path}\".",
+                canvas.width(),
+                canvas.height()
+            ),
+            Err(e) => {
+                eprintln!("Failed to write \"{path}\": {e}");
+                process::exit(1);
+            }
+        },
+        None => {
+            if let Err(e) = std::io::stdout().write_all(&rendered) {
+                eprintln!("Failed to write the visualization: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
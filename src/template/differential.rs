@@ -0,0 +1,153 @@
+/// Differential testing: check a fast solution against a slower reference implementation on generated inputs.
+///
+/// The reference is a second [`Solution`] for the same day, usually the straightforward version an optimization
+/// replaced. It parses the input on its own and only needs to solve the parts it is meant to check: parts where it
+/// returns `Ok(None)` are not compared. Inputs come from the fast solution's [`Solution::generate`].
+use crate::template::{error::Result, Solution};
+use crate::utils::Rng;
+
+/// Answers of both parts, as displayed.
+type Answers = [Result<Option<String>>; 2];
+
+fn answers<S: Solution>(input: &str) -> Answers {
+    match S::parse(input) {
+        Ok(parsed) => [
+            S::part_one(&parsed).map(|answer| answer.map(|answer| answer.to_string())),
+            S::part_two(&parsed).map(|answer| answer.map(|answer| answer.to_string())),
+        ],
+        Err(e) => [Err(e.clone()), Err(e)],
+    }
+}
+
+/// Test helper: run `Fast` and `Reference` on `cases` generated inputs and panic on the first one they disagree on,
+/// with its seed and size. Sizes grow from 1 to `size`, so that the first disagreement is usually a small input.
+pub fn check<Fast: Solution, Reference: Solution>(cases: u64, size: usize) {
+    assert!(
+        Fast::generate(&mut Rng::new(0), 1).is_some(),
+        "{} day {} has no input generator",
+        Fast::YEAR,
+        Fast::DAY
+    );
+
+    for seed in 0..cases {
+        let size =
+            1 + (size.saturating_sub(1) as u64 * seed / cases.max(2).saturating_sub(1)) as usize;
+        let input = Fast::generate(&mut Rng::new(seed), size).expect("generators do not give up");
+
+        let fast = answers::<Fast>(&input);
+        let reference = answers::<Reference>(&input);
+        for (part, (fast, reference)) in ["one", "two"].iter().zip(fast.iter().zip(&reference)) {
+            if reference == &Ok(None) {
+                continue;
+            }
+            assert_eq!(
+                fast, reference,
+                "part {part} differs from the reference for seed {seed} and size {size}, input:\n{input}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::check;
+    use crate::template::{error::Result, Solution};
+    use crate::utils::Rng;
+
+    /// Sum and maximum of a list of numbers.
+    struct Fast;
+
+    impl Solution for Fast {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Fast";
+
+        type Input<'a> = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+            Some((0..size).map(|_| format!("{}\n", rng.below(100))).collect())
+        }
+
+        fn part_one(numbers: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+            Ok(Some(numbers.iter().sum()))
+        }
+
+        fn part_two(numbers: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+            Ok(numbers.iter().max().copied())
+        }
+    }
+
+    /// Sums by counting up, and gets the maximum wrong as soon as there are two numbers.
+    struct Reference;
+
+    impl Solution for Reference {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Reference";
+
+        type Input<'a> = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Fast::parse(input)
+        }
+
+        fn part_one(numbers: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+            let mut sum = 0;
+            for &n in numbers {
+                for _ in 0..n {
+                    sum += 1;
+                }
+            }
+            Ok(Some(sum))
+        }
+
+        fn part_two(numbers: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+            Ok(numbers.first().copied())
+        }
+    }
+
+    /// Only solves part one.
+    struct PartOne;
+
+    impl Solution for PartOne {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Part one";
+
+        type Input<'a> = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Fast::parse(input)
+        }
+
+        fn part_one(numbers: &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+            Reference::part_one(numbers)
+        }
+
+        fn part_two(_numbers: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn skips_unsolved_parts() {
+        check::<Fast, PartOne>(20, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "part two differs from the reference for seed 1 and size 2")]
+    fn reports_the_first_difference() {
+        // the first input has a single number, which the wrong maximum still gets right
+        check::<Fast, Reference>(20, 20);
+    }
+}
@@ -0,0 +1,159 @@
+/// Errors of solutions, reported by the runner instead of aborting the run.
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input does not have the expected format. `line` and `column` are 1-based.
+    Parser {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input was parsed, but does not describe a puzzle the solution can solve.
+    Solver(String),
+    /// The solution panicked. Holds the panic message and its location.
+    Panic(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Parse error at the position of `fragment`, which has to be a slice of `input`.
+    /// Fragments that do not point into `input` are reported at the start of the input.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Error::Parser {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    pub fn solver(message: impl Into<String>) -> Self {
+        Error::Solver(message.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            Error::Solver(message) => write!(f, "{message}"),
+            Error::Panic(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parse `fragment`, a slice of `input`, reporting its position if it is not a valid `T`.
+pub fn parse_at<T>(input: &str, fragment: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fragment.parse().map_err(|e| {
+        Error::at(
+            input,
+            fragment,
+            format!("invalid value \"{fragment}\": {e}"),
+        )
+    })
+}
+
+/// Split `fragment`, a slice of `input`, once at `delimiter`, reporting its position if the delimiter is missing.
+pub fn split_once_at<'a>(
+    input: &str,
+    fragment: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str)> {
+    fragment
+        .split_once(delimiter)
+        .ok_or_else(|| Error::at(input, fragment, format!("expected {delimiter:?}")))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_at, split_once_at, Error};
+
+    #[test]
+    fn locates_fragments() {
+        let input = "1 2\n3 x\n";
+        let fragment = &input[6..7];
+
+        assert_eq!(
+            Error::at(input, fragment, "oops"),
+            Error::Parser {
+                line: 2,
+                column: 3,
+                message: "oops".into()
+            }
+        );
+        assert_eq!(
+            Error::at(input, &input[..1], "oops").to_string(),
+            "parse error at line 1, column 1: oops"
+        );
+    }
+
+    #[test]
+    fn locates_foreign_fragments_at_start() {
+        let input = "1 2\n3 x\n";
+        assert!(matches!(
+            Error::at(input, &String::from("x"), "oops"),
+            Error::Parser {
+                line: 1,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn counts_columns_in_chars() {
+        let input = "ä ö\nü x";
+        let fragment = &input[input.len() - 1..];
+        assert!(matches!(
+            Error::at(input, fragment, "oops"),
+            Error::Parser {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parses_values() {
+        let input = "seeds: 79 x4";
+        assert_eq!(parse_at::<u64>(input, &input[7..9]), Ok(79));
+        assert_eq!(
+            parse_at::<u64>(input, &input[10..])
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, column 11: invalid value \"x4\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn splits_fragments() {
+        let input = "a -> b\nc d";
+        assert_eq!(split_once_at(input, &input[..6], " -> "), Ok(("a", "b")));
+        assert_eq!(
+            split_once_at(input, &input[7..], " -> ")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 2, column 1: expected \" -> \""
+        );
+    }
+}
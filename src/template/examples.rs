@@ -0,0 +1,320 @@
+/// Example inputs of a day and the answers they are expected to produce.
+///
+/// A day can have any number of examples in `data/YYYY/examples/`: `NN.txt` and `NN-<name>.txt`, e.g. `01-2.txt`
+/// for a separate part two example or `10-a.txt`, `10-b.txt` for several small ones. The expected answers of an
+/// example live next to it in the answers format, e.g. `01-2.toml` with `part_two = "281"`, together with the
+/// [`Parameters`] the example needs.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::template::{
+    answers::{self, Answers},
+    Parameters, Solution,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    /// File name without extension, e.g. `01-2`.
+    pub name: String,
+    pub input: String,
+    /// Expected answers. Parts without one are not checked.
+    pub answers: Answers,
+    pub parameters: Parameters,
+}
+
+/// Contents of the answers file of an example.
+#[derive(Debug, Default, Deserialize)]
+struct Expected {
+    #[serde(flatten)]
+    answers: Answers,
+    #[serde(default)]
+    parameters: Parameters,
+}
+
+fn load_expected(path: &Path) -> Result<Expected, answers::Error> {
+    match fs::read_to_string(path) {
+        Ok(s) => toml::from_str(&s).map_err(|e| answers::Error::Parser(e.to_string())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Expected::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Whether `name`, a file name without extension, is an example of `day`.
+fn is_example_of(name: &str, day: u8) -> bool {
+    let day = format!("{day:02}");
+    name.strip_prefix(&day)
+        .is_some_and(|rest| rest.is_empty() || rest.len() > 1 && rest.starts_with('-'))
+}
+
+/// All examples of a day, sorted by name so that `NN.txt` comes first.
+pub fn list(year: u16, day: u8) -> Result<Vec<Example>, answers::Error> {
+    let dir = PathBuf::from("data")
+        .join(year.to_string())
+        .join("examples");
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if !is_example_of(name, day) {
+            continue;
+        }
+
+        let expected = load_expected(&path.with_extension("toml"))?;
+        examples.push(Example {
+            name: name.to_string(),
+            input: fs::read_to_string(&path)?,
+            answers: expected.answers,
+            parameters: expected.parameters,
+        });
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Run a solution against one example, panicking with the example's name if it does not produce the expected answers.
+pub fn check_example<S: Solution>(example: &Example) {
+    let input = S::parse_with(&example.input, &example.parameters)
+        .unwrap_or_else(|e| panic!("example {} could not be parsed: {e}", example.name));
+
+    if let Some(expected) = example.answers.get(1) {
+        let actual = S::part_one(&input).map(|answer| answer.map(|answer| answer.to_string()));
+        assert_eq!(
+            actual,
+            Ok(Some(expected.to_string())),
+            "part one of example {}",
+            example.name
+        );
+    }
+
+    if let Some(expected) = example.answers.get(2) {
+        let actual = S::part_two(&input).map(|answer| answer.map(|answer| answer.to_string()));
+        assert_eq!(
+            actual,
+            Ok(Some(expected.to_string())),
+            "part two of example {}",
+            example.name
+        );
+    }
+}
+
+/// Test helper: run a solution against every example of its day that has expected answers.
+/// Days without any are skipped with a note, since example files are not committed with the solutions.
+pub fn check<S: Solution>() {
+    let examples = list(S::YEAR, S::DAY).unwrap_or_else(|e| panic!("{e}"));
+    let examples: Vec<_> = examples
+        .iter()
+        .filter(|example| example.answers != Answers::default())
+        .collect();
+    if examples.is_empty() {
+        eprintln!(
+            "no example of {} day {} has expected answers, skipping",
+            S::YEAR,
+            S::DAY
+        );
+        return;
+    }
+
+    for example in examples {
+        check_example::<S>(example);
+    }
+}
+
+/// Example and answer of one part of a puzzle description, as far as they can be guessed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extracted {
+    /// All code blocks of the part, the example is usually the first one.
+    pub blocks: Vec<String>,
+    /// The last emphasized code span outside of code blocks, which is where the answer for the example usually is.
+    pub answer: Option<String>,
+}
+
+impl Extracted {
+    #[must_use]
+    pub fn example(&self) -> Option<&str> {
+        self.blocks.first().map(String::as_str)
+    }
+}
+
+/// Extract the examples of both parts from a puzzle description downloaded with `cargo download`.
+/// Part two is `None` until it is unlocked.
+#[must_use]
+pub fn extract(markdown: &str) -> (Extracted, Option<Extracted>) {
+    match markdown.split_once("--- Part Two ---") {
+        Some((part_one, part_two)) => (extract_part(part_one), Some(extract_part(part_two))),
+        None => (extract_part(markdown), None),
+    }
+}
+
+fn extract_part(markdown: &str) -> Extracted {
+    let mut extracted = Extracted::default();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (line == "```", &mut block) {
+            (true, None) => block = Some(String::new()),
+            (true, Some(_)) => extracted.blocks.extend(block.take()),
+            (false, Some(block)) => {
+                block.push_str(line);
+                block.push('\n');
+            }
+            (false, None) => {
+                // answers are emphasized code spans: `*142*`
+                for span in line.split('`').skip(1).step_by(2) {
+                    if let Some(answer) = span.strip_prefix('*').and_then(|s| s.strip_suffix('*')) {
+                        extracted.answer = Some(answer.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    extracted
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_example, extract, is_example_of, Example, Extracted};
+    use crate::template::{answers::Answers, error::Result, Parameters, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+
+        type Input<'a> = (Vec<u32>, u32);
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Self::parse_with(input, &Parameters::default())
+        }
+
+        fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Input<'a>> {
+            let numbers = input.lines().map(|line| line.parse().unwrap()).collect();
+            Ok((numbers, parameters.get_or("factor", 1)?))
+        }
+
+        fn part_one((numbers, factor): &Self::Input<'_>) -> Result<Option<Self::PartOne>> {
+            Ok(Some(numbers.iter().sum::<u32>() * factor))
+        }
+
+        fn part_two(_input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>> {
+            Ok(None)
+        }
+    }
+
+    fn example(part_one: Option<&str>, part_two: Option<&str>) -> Example {
+        Example {
+            name: "01-a".into(),
+            input: "1\n2\n".into(),
+            answers: Answers {
+                part_one: part_one.map(Into::into),
+                part_two: part_two.map(Into::into),
+            },
+            parameters: Parameters::new(),
+        }
+    }
+
+    #[test]
+    fn matches_file_names() {
+        assert!(is_example_of("01", 1));
+        assert!(is_example_of("01-2", 1));
+        assert!(is_example_of("10-ab", 10));
+        assert!(!is_example_of("10", 1));
+        assert!(!is_example_of("01-", 1));
+        assert!(!is_example_of("011", 1));
+    }
+
+    #[test]
+    fn checks_expected_parts() {
+        check_example::<Sum>(&example(Some("3"), None));
+    }
+
+    #[test]
+    fn passes_parameters() {
+        let mut example = example(Some("30"), None);
+        example.parameters = Parameters::new().with("factor", 10);
+        check_example::<Sum>(&example);
+    }
+
+    #[test]
+    #[should_panic(expected = "part one of example 01-a")]
+    fn reports_wrong_answers() {
+        check_example::<Sum>(&example(Some("4"), None));
+    }
+
+    #[test]
+    #[should_panic(expected = "part two of example 01-a")]
+    fn reports_unsolved_parts() {
+        check_example::<Sum>(&example(None, Some("3")));
+    }
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let markdown = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "```",
+            "",
+            "Adding these together produces `*142*`. What is the `sum`?",
+            "",
+            "## --- Part Two ---",
+            "",
+            "```",
+            "two1nine",
+            "```",
+            "",
+            "```",
+            "29",
+            "```",
+            "",
+            "Adding these together produces `*281*`.",
+        ]
+        .join("\n");
+
+        let (part_one, part_two) = extract(&markdown);
+        assert_eq!(
+            part_one,
+            Extracted {
+                blocks: vec!["1abc2\npqr3stu8vwx\n".into()],
+                answer: Some("142".into()),
+            }
+        );
+
+        let part_two = part_two.unwrap();
+        assert_eq!(part_two.example(), Some("two1nine\n"));
+        assert_eq!(part_two.blocks.len(), 2);
+        assert_eq!(part_two.answer.as_deref(), Some("281"));
+    }
+
+    #[test]
+    fn extracts_locked_part_two() {
+        let (part_one, part_two) = extract("No examples, `*emphasis*` only.\n");
+        assert_eq!(part_one.example(), None);
+        assert_eq!(part_one.answer.as_deref(), Some("emphasis"));
+        assert_eq!(part_two, None);
+    }
+}
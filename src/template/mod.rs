@@ -1,9 +1,22 @@
-use std::{env, fs};
+use std::{env, fs, path::PathBuf};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
+pub mod baseline;
 pub mod commands;
+pub mod differential;
+pub mod error;
+pub mod examples;
+pub mod parameters;
 pub mod readme_benchmarks;
+pub mod report;
 pub mod runner;
+pub mod solution;
+pub mod stats;
+pub mod submissions;
+
+pub use parameters::Parameters;
+pub use solution::{Day, Solution};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -11,22 +24,52 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day:02}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning `None` if it cannot be read.
+#[must_use]
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Option<String> {
+    fs::read_to_string(get_data_path(folder, year, day, "txt")).ok()
+}
+
+/// Helper function that reads one of several files of a day, e.g. the part two example `data/2023/examples/01-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, year: u16, day: u8, part: &str) -> String {
+    fs::read_to_string(get_data_path_part(folder, year, day, part, "txt"))
+        .expect("could not open input file")
 }
 
-/// main! produces a block setting up the input and runner for each part.
-#[macro_export]
-macro_rules! main {
-    ($day:expr) => {
-        fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", $day);
-            run_part(part_one, &input, $day, 1);
-            run_part(part_two, &input, $day, 2);
-        }
-    };
+/// Path of a file belonging to a day, e.g. `data/2023/inputs/01.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, year: u16, day: u8, extension: &str) -> PathBuf {
+    PathBuf::from("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(format!("{day:02}.{extension}"))
+}
+
+/// Path of one of several files of a day, named after the day and `part`, e.g. `data/2023/examples/01-2.txt`.
+#[must_use]
+pub fn get_data_path_part(
+    folder: &str,
+    year: u16,
+    day: u8,
+    part: &str,
+    extension: &str,
+) -> PathBuf {
+    PathBuf::from("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(format!("{day:02}-{part}.{extension}"))
+}
+
+/// Year to operate on if none was given on the command line, read from `AOC_YEAR`.
+#[must_use]
+pub fn default_year() -> Option<u16> {
+    match env::var("AOC_YEAR") {
+        Ok(x) => x.parse().ok().or(None),
+        Err(_) => None,
+    }
 }
@@ -0,0 +1,63 @@
+/// Puzzle constants that differ between the examples and the real input, like the number of steps to walk.
+///
+/// Solutions read them in `Solution::parse_with`, falling back to the values of the real puzzle. Examples set them
+/// in the `[parameters]` table of their answers file, e.g. `21.toml`:
+///
+/// ```toml
+/// part_one = "16"
+///
+/// [parameters]
+/// steps = 6
+/// ```
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::template::error::{Error, Result};
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Parameters(toml::Table);
+
+impl Parameters {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set parameter `name`, e.g. to run a solution on an example from a test.
+    #[must_use]
+    pub fn with(mut self, name: &str, value: impl Into<toml::Value>) -> Self {
+        self.0.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// Value of parameter `name`, or `default` if it is not set. Values of the wrong type are an error.
+    pub fn get_or<T: DeserializeOwned>(&self, name: &str, default: T) -> Result<T> {
+        match self.0.get(name) {
+            Some(value) => value
+                .clone()
+                .try_into()
+                .map_err(|e| Error::solver(format!("invalid parameter \"{name}\": {e}"))),
+            None => Ok(default),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Parameters;
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let parameters = Parameters::new().with("steps", 6);
+        assert_eq!(parameters.get_or("steps", 64), Ok(6));
+        assert_eq!(parameters.get_or("presses", 1000), Ok(1000));
+        assert_eq!(parameters.get_or("steps", 64.0), Ok(6.0));
+        assert!(parameters.get_or("steps", String::new()).is_err());
+    }
+
+    #[test]
+    fn parses_tables() {
+        let parameters: Parameters = toml::from_str("min = 7\nmax = 27").unwrap();
+        assert_eq!(parameters, Parameters::new().with("min", 7).with("max", 27));
+    }
+}
@@ -2,6 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::runner::DayResult;
+
 static MARKER: &str = "<!--- benchmarking table --->";
 
 #[derive(Debug)]
@@ -18,21 +20,50 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
+    pub year: u16,
     pub day: usize,
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
 }
 
+impl Timings {
+    /// Collect the timings of a day from the results of running it.
+    #[must_use]
+    pub fn from_results(year: u16, day: usize, result: &DayResult) -> Self {
+        let timing_str = |part: u8| {
+            result
+                .parts
+                .iter()
+                .find(|result| result.part == part && result.answer.is_some())
+                .map(|result| format!("{:.1?}", result.duration))
+        };
+
+        Self {
+            year,
+            day,
+            parse: result
+                .parse
+                .error
+                .is_none()
+                .then(|| format!("{:.1?}", result.parse.duration)),
+            part_1: timing_str(1),
+            part_2: timing_str(2),
+            total_nanos: result.total_duration().as_nanos() as f64,
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: usize) -> String {
+pub fn get_path_for_day(year: u16, day: usize) -> String {
     let day_padded = format!("{day:02}");
-    format!("./src/bin/{day_padded}.rs")
+    format!("./src/solutions/year{year}/day{day_padded}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -64,16 +95,23 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
+    // only label rows with their year if the table spans multiple seasons.
+    let is_multi_year = timings.iter().any(|timing| timing.year != timings[0].year);
+
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_day(timing.year, timing.day);
+        let label = if is_multi_year {
+            format!("{} Day {}", timing.year, timing.day)
+        } else {
+            format!("Day {}", timing.day)
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day,
-            path,
+            "| [{label}]({path}) | `{}` | `{}` | `{}` |",
+            timing.parse.unwrap_or_else(|| "-".into()),
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
         ));
@@ -108,19 +146,25 @@ mod tests {
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
+                year: 2023,
                 day: 1,
+                parse: Some("5ms".into()),
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
             },
             Timings {
+                year: 2023,
                 day: 2,
+                parse: Some("0ms".into()),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
             },
             Timings {
+                year: 2023,
                 day: 4,
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
@@ -168,11 +212,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/solutions/year2023/day01.rs) | `5ms` | `10ms` | `20ms` |",
+            "| [Day 2](./src/solutions/year2023/day02.rs) | `0ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/solutions/year2023/day04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -181,4 +225,15 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn labels_rows_with_years() {
+        let mut timings = get_mock_timings();
+        timings[0].year = 2022;
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [2022 Day 1](./src/solutions/year2022/day01.rs) |"));
+        assert!(s.contains("| [2023 Day 2](./src/solutions/year2023/day02.rs) |"));
+    }
 }
@@ -0,0 +1,344 @@
+/// Machine-readable output of solution results, for scripts that would otherwise scrape the text output.
+use std::{fmt::Display, str::FromStr};
+
+use serde::Serialize;
+
+use crate::template::{
+    runner::{DayResult, ParseResult, PartResult},
+    stats::BenchStats,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output, decorated with ANSI escape codes.
+    #[default]
+    Text,
+    /// A single JSON array containing all records, printed once every day has run.
+    Json,
+    /// One JSON record per line, printed as soon as a day has run.
+    Ndjson,
+}
+
+impl OutputFormat {
+    #[must_use]
+    pub fn is_text(self) -> bool {
+        self == OutputFormat::Text
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" => Ok(OutputFormat::Ndjson),
+            _ => Err(format!(
+                "unknown format \"{s}\", expected one of: text, json, ndjson"
+            )),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Ndjson => write!(f, "ndjson"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The part returned an error or panicked.
+    Failed,
+    /// The part did not run because the input could not be parsed.
+    Skipped,
+}
+
+/// A single output record, tagged with the stage it belongs to.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "stage", rename_all = "lowercase")]
+pub enum Record {
+    Parse(ParseRecord),
+    Part(PartRecord),
+}
+
+/// Result of parsing the input of a day.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParseRecord {
+    pub year: u16,
+    pub day: u8,
+    pub duration_nanos: u64,
+    pub samples: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<StatsRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of running one part of a day.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub duration_nanos: u64,
+    pub samples: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<StatsRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Benchmark statistics of a timed run, in nanoseconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsRecord {
+    pub mean: u64,
+    pub p50: u64,
+    pub p95: u64,
+    pub p99: u64,
+    pub min: u64,
+    pub max: u64,
+    pub std_dev: u64,
+    pub outliers: usize,
+}
+
+#[allow(clippy::cast_possible_truncation)]
+impl From<&BenchStats> for StatsRecord {
+    fn from(stats: &BenchStats) -> Self {
+        Self {
+            mean: stats.mean.as_nanos() as u64,
+            p50: stats.p50.as_nanos() as u64,
+            p95: stats.p95.as_nanos() as u64,
+            p99: stats.p99.as_nanos() as u64,
+            min: stats.min.as_nanos() as u64,
+            max: stats.max.as_nanos() as u64,
+            std_dev: stats.std_dev.as_nanos() as u64,
+            outliers: stats.outliers,
+        }
+    }
+}
+
+impl ParseRecord {
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(year: u16, day: u8, result: &ParseResult) -> Self {
+        Self {
+            year,
+            day,
+            duration_nanos: result.duration.as_nanos() as u64,
+            samples: result.samples as u64,
+            stats: result.stats.as_ref().map(StatsRecord::from),
+            error: result.error.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+impl PartRecord {
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(year: u16, day: u8, result: &PartResult) -> Self {
+        Self {
+            year,
+            day,
+            part: result.part,
+            status: if result.error.is_some() {
+                Status::Failed
+            } else if result.answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer: result.answer.clone(),
+            duration_nanos: result.duration.as_nanos() as u64,
+            samples: result.samples as u64,
+            stats: result.stats.as_ref().map(StatsRecord::from),
+            error: result.error.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+/// Collects records and prints them according to the output format.
+/// Nothing is printed for [`OutputFormat::Text`], as the runner already printed its results.
+pub struct Reporter {
+    format: OutputFormat,
+    records: Vec<Record>,
+}
+
+impl Reporter {
+    #[must_use]
+    pub fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            records: vec![],
+        }
+    }
+
+    pub fn add(&mut self, year: u16, day: u8, result: &DayResult) {
+        let parse = Record::Parse(ParseRecord::new(year, day, &result.parse));
+        let is_skipped = result.parse.error.is_some();
+        let parts = result.parts.iter().map(|part| {
+            let mut record = PartRecord::new(year, day, part);
+            if is_skipped {
+                record.status = Status::Skipped;
+            }
+            Record::Part(record)
+        });
+        let records = std::iter::once(parse).chain(parts);
+
+        match self.format {
+            OutputFormat::Text => {}
+            OutputFormat::Json => self.records.extend(records),
+            OutputFormat::Ndjson => records.for_each(|record| println!("{}", to_json(&record))),
+        }
+    }
+
+    pub fn finish(self) {
+        if self.format == OutputFormat::Json {
+            println!("{}", to_json(&self.records));
+        }
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("records are always serializable")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{to_json, OutputFormat, ParseRecord, PartRecord, Record};
+    use crate::template::{
+        error::Error,
+        runner::{ParseResult, PartResult},
+        stats::BenchStats,
+    };
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!("ndjson".parse(), Ok(OutputFormat::Ndjson));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn serializes_solved_part() {
+        let result = PartResult {
+            part: 1,
+            answer: Some("42".into()),
+            error: None,
+            duration: Duration::from_nanos(74130),
+            samples: 10000,
+            stats: None,
+        };
+
+        assert_eq!(
+            to_json(&PartRecord::new(2023, 3, &result)),
+            r#"{"year":2023,"day":3,"part":1,"status":"solved","answer":"42","duration_nanos":74130,"samples":10000}"#
+        );
+    }
+
+    #[test]
+    fn serializes_unsolved_part() {
+        let result = PartResult {
+            part: 2,
+            answer: None,
+            error: None,
+            duration: Duration::from_nanos(10),
+            samples: 1,
+            stats: None,
+        };
+
+        assert_eq!(
+            to_json(&PartRecord::new(2023, 25, &result)),
+            r#"{"year":2023,"day":25,"part":2,"status":"unsolved","answer":null,"duration_nanos":10,"samples":1}"#
+        );
+    }
+
+    #[test]
+    fn serializes_failed_part() {
+        let result = PartResult {
+            part: 1,
+            answer: None,
+            error: Some(Error::solver("no path to the goal")),
+            duration: Duration::from_nanos(10),
+            samples: 1,
+            stats: None,
+        };
+
+        assert_eq!(
+            to_json(&PartRecord::new(2023, 17, &result)),
+            r#"{"year":2023,"day":17,"part":1,"status":"failed","answer":null,"duration_nanos":10,"samples":1,"error":"no path to the goal"}"#
+        );
+    }
+
+    #[test]
+    fn serializes_parse_error() {
+        let result = ParseResult {
+            error: Some(Error::at("x", "x", "expected a number")),
+            duration: Duration::from_nanos(10),
+            samples: 1,
+            stats: None,
+        };
+
+        assert_eq!(
+            to_json(&Record::Parse(ParseRecord::new(2023, 5, &result))),
+            r#"{"stage":"parse","year":2023,"day":5,"duration_nanos":10,"samples":1,"error":"parse error at line 1, column 1: expected a number"}"#
+        );
+    }
+
+    #[test]
+    fn serializes_stats() {
+        let result = PartResult {
+            part: 1,
+            answer: Some("42".into()),
+            error: None,
+            duration: Duration::from_nanos(12),
+            samples: 10,
+            stats: BenchStats::from_samples(&[Duration::from_nanos(12); 10]),
+        };
+
+        assert_eq!(
+            to_json(&PartRecord::new(2023, 1, &result)),
+            r#"{"year":2023,"day":1,"part":1,"status":"solved","answer":"42","duration_nanos":12,"samples":10,"stats":{"mean":12,"p50":12,"p95":12,"p99":12,"min":12,"max":12,"std_dev":0,"outliers":0}}"#
+        );
+    }
+
+    #[test]
+    fn tags_records_with_stage() {
+        let parse = ParseResult {
+            error: None,
+            duration: Duration::from_nanos(500),
+            samples: 1,
+            stats: None,
+        };
+        let part = PartResult {
+            part: 1,
+            answer: Some("42".into()),
+            error: None,
+            duration: Duration::from_nanos(74130),
+            samples: 1,
+            stats: None,
+        };
+
+        assert_eq!(
+            to_json(&Record::Parse(ParseRecord::new(2023, 5, &parse))),
+            r#"{"stage":"parse","year":2023,"day":5,"duration_nanos":500,"samples":1}"#
+        );
+        assert_eq!(
+            to_json(&Record::Part(PartRecord::new(2023, 5, &part))),
+            r#"{"stage":"part","year":2023,"day":5,"part":1,"status":"solved","answer":"42","duration_nanos":74130,"samples":1}"#
+        );
+    }
+}
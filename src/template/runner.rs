@@ -1,62 +1,260 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    error::{Error, Result},
+    report::OutputFormat,
+    stats::BenchStats,
+    ANSI_ITALIC, ANSI_RESET,
+};
+use std::cmp;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: u8, part: u8) {
+/// Outcome of running a single part of a solution.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Set if the part failed, in which case there is no answer.
+    pub error: Option<Error>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// Benchmark statistics, only present for timed runs.
+    pub stats: Option<BenchStats>,
+}
+
+impl PartResult {
+    /// Result of a part that did not run because the input could not be parsed.
+    #[must_use]
+    pub fn skipped(part: u8) -> Self {
+        Self {
+            part,
+            answer: None,
+            error: None,
+            duration: Duration::ZERO,
+            samples: 0,
+            stats: None,
+        }
+    }
+}
+
+/// Outcome of the parse stage, which is shared by both parts and timed separately.
+#[derive(Debug, Clone)]
+pub struct ParseResult {
+    /// Set if the input could not be parsed, in which case both parts are skipped.
+    pub error: Option<Error>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// Benchmark statistics, only present for timed runs.
+    pub stats: Option<BenchStats>,
+}
+
+/// Outcome of running a day: the parse stage followed by both parts.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub parse: ParseResult,
+    pub parts: [PartResult; 2],
+}
+
+impl DayResult {
+    /// Combined duration of the parse stage and all solved parts.
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.parse.duration
+            + self
+                .parts
+                .iter()
+                .filter(|result| result.answer.is_some())
+                .map(|result| result.duration)
+                .sum::<Duration>()
+    }
+
+    /// Errors of the parse stage and both parts.
+    pub fn errors(&self) -> impl Iterator<Item = &Error> {
+        self.parse
+            .error
+            .iter()
+            .chain(self.parts.iter().filter_map(|result| result.error.as_ref()))
+    }
+}
+
+/// Options controlling how solutions are run and how their results are printed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    pub format: OutputFormat,
+    /// Suppress all output of the runner, e.g. when only the answers are of interest.
+    pub is_quiet: bool,
+}
+
+impl RunOptions {
+    /// Whether results should be printed as they come in.
+    #[must_use]
+    pub fn is_verbose(&self) -> bool {
+        !self.is_quiet && self.format.is_text()
+    }
+}
+
+/// Run the parse stage and return its output, so it can be shared by both parts.
+/// Returns `None` if the input could not be parsed.
+pub fn run_parse<'a, T>(
+    func: impl Fn(&'a str) -> Result<T>,
+    input: &'a str,
+    options: RunOptions,
+) -> (Option<T>, ParseResult) {
+    let is_verbose = options.is_verbose();
+
+    let (parsed, duration, samples, stats) = run_timed(func, input, options.is_timed, |parsed| {
+        if is_verbose && options.is_timed && parsed.is_ok() {
+            print!("Parse: > {ANSI_ITALIC}benching{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
+    });
+
+    if is_verbose {
+        if options.is_timed {
+            print!("\r");
+        }
+        match &parsed {
+            Ok(_) => println!("Parse:{}", format_duration(&duration, samples)),
+            Err(e) => println!("Parse: ✖ {e}"),
+        }
+        if let Some(stats) = &stats {
+            print_stats(stats);
+        }
+    }
+
+    match parsed {
+        Ok(parsed) => (
+            Some(parsed),
+            ParseResult {
+                error: None,
+                duration,
+                samples,
+                stats,
+            },
+        ),
+        Err(e) => (
+            None,
+            ParseResult {
+                error: Some(e),
+                duration,
+                samples,
+                stats,
+            },
+        ),
+    }
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Result<Option<T>>,
+    input: I,
+    part: u8,
+    options: RunOptions,
+) -> PartResult {
     let part_str = format!("Part {part}");
+    let is_verbose = options.is_verbose();
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples, stats) = run_timed(func, input, options.is_timed, |result| {
+        if is_verbose {
+            print_result(result, &part_str, "");
+            if options.is_timed && result.is_ok() {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        }
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if is_verbose {
+        print_result(&result, &part_str, &format_duration(&duration, samples));
+        if let Some(stats) = &stats {
+            print_stats(stats);
+        }
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let (answer, error) = match result {
+        Ok(answer) => (answer.map(|answer| answer.to_string()), None),
+        Err(e) => (None, Some(e)),
+    };
+
+    PartResult {
+        part,
+        answer,
+        error,
+        duration,
+        samples,
+        stats,
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether timing was requested:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched and the mean of the samples that are not outliers is reported.
+///
+/// Panics of the first run are turned into errors. Failed runs are not benched.
 fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+    func: impl Fn(I) -> Result<T>,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    is_timed: bool,
+    hook: impl Fn(&Result<T>),
+) -> (Result<T>, Duration, u128, Option<BenchStats>) {
     let timer = Instant::now();
-    let result = func(input.clone());
+    let result = catch_panic(|| func(input.clone()));
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if is_timed && result.is_ok() {
+        let (stats, samples) = bench(func, input, &base_time);
+        (result, stats.mean, samples, Some(stats))
     } else {
-        (base_time, 1)
-    };
+        (result, base_time, 1, None)
+    }
+}
+
+/// Run `func`, turning a panic into an [`Error::Panic`] so that a broken day does not abort the whole run.
+/// The default panic hook is silenced meanwhile, the panic message is reported as part of the error instead.
+fn catch_panic<T>(func: impl FnOnce() -> Result<T>) -> Result<T> {
+    let message = Arc::new(Mutex::new(None));
 
-    (result, run.0, run.1)
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new({
+        let message = Arc::clone(&message);
+        move |info| {
+            if let Ok(mut message) = message.lock() {
+                message.get_or_insert_with(|| info.to_string().replace('\n', " "));
+            }
+        }
+    }));
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    panic::set_hook(default_hook);
+
+    result.unwrap_or_else(|_| {
+        let message = message.lock().ok().and_then(|message| message.clone());
+        Err(Error::Panic(
+            message.unwrap_or_else(|| "solution panicked".into()),
+        ))
+    })
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Warm up for approx. 100ms (at least once), then bench approx. 1 second of execution time or 10 samples,
+/// whatever takes longer. The number of samples is derived from the average time of the warm-up runs.
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (BenchStats, u128) {
+    let warmup_iterations =
+        (Duration::from_millis(100).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(1, 1000);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let warmup_timer = Instant::now();
+    for _ in 0..warmup_iterations {
+        func(input.clone());
+    }
+    let warmup_time = warmup_timer.elapsed().as_nanos() / warmup_iterations;
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(warmup_time, 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -68,19 +266,8 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
-
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+    let stats = BenchStats::from_samples(&timers).expect("benches take at least 10 samples");
+    (stats, bench_iterations)
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -91,11 +278,23 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_stats(stats: &BenchStats) {
+    let outliers = match stats.outliers {
+        1 => "1 outlier".to_string(),
+        n => format!("{n} outliers"),
+    };
+
+    println!(
+        "  {ANSI_ITALIC}p50 {:.1?} · p95 {:.1?} · p99 {:.1?} · min {:.1?} · max {:.1?} · σ {:.1?} · {outliers}{ANSI_RESET}",
+        stats.p50, stats.p95, stats.p99, stats.min, stats.max, stats.std_dev
+    );
+}
+
+fn print_result<T: Display>(result: &Result<Option<T>>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(Some(result)) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -115,7 +314,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -123,44 +322,13 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(e) => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: ✖ {e}");
+            }
+        }
     }
 }
-
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: u8,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
-
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
-
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
-
-    if part_submit != part {
-        return None;
-    }
-
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
-}
@@ -0,0 +1,119 @@
+/// Common interface for solutions so that days can be enumerated and run in-process.
+use std::fmt::Display;
+
+use crate::template::{
+    error::Result,
+    runner::{self, DayResult, PartResult, RunOptions},
+    Parameters,
+};
+use crate::utils::{Canvas, Rng};
+
+/// A solution for a single day.
+///
+/// `parse` turns the raw puzzle input into the representation shared by both parts.
+/// It runs once per day and is timed separately from the parts.
+///
+/// Malformed inputs are reported as errors instead of panics, so that the runner can print a diagnostic
+/// and move on to the next day. Parts return `Ok(None)` while they are not solved yet.
+pub trait Solution {
+    /// Year of the event the puzzle belongs to.
+    const YEAR: u16;
+    /// Day of the puzzle, `1..=25`.
+    const DAY: u8;
+    /// Title of the puzzle as listed on adventofcode.com.
+    const TITLE: &'static str;
+
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    /// Parse `input` with puzzle constants that examples may change, like the number of steps to walk.
+    /// Solutions with such constants implement this and let `parse` call it with the default [`Parameters`].
+    fn parse_with<'a>(input: &'a str, parameters: &Parameters) -> Result<Self::Input<'a>> {
+        let _ = parameters;
+        Self::parse(input)
+    }
+
+    /// Generate a valid input of about `size` elements (lines, bricks, …), for property tests and for measuring how
+    /// the solution scales. Days without a generator return `None`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let _ = (rng, size);
+        None
+    }
+
+    /// Draw what `part` computes on `input`, like the loop through a maze, for `cargo visualize`.
+    /// Days without a visualization return `Ok(None)`.
+    fn visualize(input: &Self::Input<'_>, part: u8) -> Result<Option<Canvas>> {
+        let _ = (input, part);
+        Ok(None)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>>;
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>>;
+}
+
+/// Object-safe view of a [`Solution`], used by the registry to hold days of different types.
+pub trait Day: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+
+    /// Parse `input` and run both parts against it. With text output, results are printed as they come in.
+    /// Parts are skipped if the input could not be parsed.
+    fn run(&self, input: &str, options: RunOptions) -> DayResult;
+
+    /// Generate an input of about `size` elements from `seed`, if the day has a generator.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    /// Parse `input` and draw what `part` computes on it, if the day has a visualization.
+    fn visualize(&self, input: &str, part: u8) -> Result<Option<Canvas>>;
+
+    /// Parse `input` and solve `part` of it quietly, returning the answer as displayed.
+    fn solve(&self, input: &str, part: u8) -> Result<Option<String>>;
+}
+
+impl<S: Solution + Sync> Day for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn run(&self, input: &str, options: RunOptions) -> DayResult {
+        let (parsed, parse) = runner::run_parse(S::parse, input, options);
+
+        let parts = match parsed {
+            Some(parsed) => [
+                runner::run_part(S::part_one, &parsed, 1, options),
+                runner::run_part(S::part_two, &parsed, 2, options),
+            ],
+            None => [PartResult::skipped(1), PartResult::skipped(2)],
+        };
+
+        DayResult { parse, parts }
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn visualize(&self, input: &str, part: u8) -> Result<Option<Canvas>> {
+        S::visualize(&S::parse(input)?, part)
+    }
+
+    fn solve(&self, input: &str, part: u8) -> Result<Option<String>> {
+        let parsed = S::parse(input)?;
+        Ok(match part {
+            1 => S::part_one(&parsed)?.map(|answer| answer.to_string()),
+            _ => S::part_two(&parsed)?.map(|answer| answer.to_string()),
+        })
+    }
+}
@@ -0,0 +1,132 @@
+/// Summary statistics over benchmark samples.
+use std::time::Duration;
+
+/// Statistics of a benchmark run.
+///
+/// Percentiles (p50, p95 and p99) and min/max are taken from all samples, so they are the observed ones. Mean and
+/// standard deviation are computed after outliers have been rejected, since a single slow sample skews them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    /// Mean of the samples that were kept.
+    pub mean: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    /// Standard deviation of the samples that were kept.
+    pub std_dev: Duration,
+    /// Number of samples that were kept for the mean and standard deviation.
+    pub samples: usize,
+    /// Number of samples rejected as outliers.
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Compute statistics for `timers`, rejecting samples outside of Tukey's fences (1.5 × IQR) for the mean and
+    /// standard deviation. Returns `None` if there are no samples.
+    #[must_use]
+    pub fn from_samples(timers: &[Duration]) -> Option<Self> {
+        if timers.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<f64> = timers.iter().map(|t| t.as_nanos() as f64).collect();
+        sorted.sort_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let fence = 1.5 * (q3 - q1);
+
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|&t| t >= q1 - fence && t <= q3 + fence)
+            .collect();
+
+        let mean = kept.iter().sum::<f64>() / kept.len() as f64;
+        let variance = kept.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / kept.len() as f64;
+
+        Some(Self {
+            mean: from_nanos(mean),
+            p50: from_nanos(percentile(&sorted, 50.0)),
+            p95: from_nanos(percentile(&sorted, 95.0)),
+            p99: from_nanos(percentile(&sorted, 99.0)),
+            min: from_nanos(sorted[0]),
+            max: from_nanos(sorted[sorted.len() - 1]),
+            std_dev: from_nanos(variance.sqrt()),
+            samples: kept.len(),
+            outliers: sorted.len() - kept.len(),
+        })
+    }
+}
+
+/// Percentile of an ascending slice by linear interpolation between the closest ranks.
+fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+    let rank = percentile / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[100])).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.p99, Duration::from_nanos(100));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_percentiles() {
+        let samples: Vec<u64> = (1..=101).collect();
+        let stats = BenchStats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(51));
+        assert_eq!(stats.p50, Duration::from_nanos(51));
+        assert_eq!(stats.p95, Duration::from_nanos(96));
+        assert_eq!(stats.p99, Duration::from_nanos(100));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(101));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_std_dev() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.std_dev, Duration::from_nanos(14));
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.mean, Duration::from_nanos(11));
+        // the extremes are the observed ones
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(1000));
+    }
+}
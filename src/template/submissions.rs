@@ -0,0 +1,461 @@
+/// History of submitted answers, used to avoid submitting answers that are known to be wrong.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::get_data_path;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse submissions: {e}"),
+            Error::IO(e) => write!(f, "could not access submissions: {e}"),
+        }
+    }
+}
+
+/// Verdict of the server on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently, the server asks to wait for `wait_secs`.
+    RateLimited {
+        wait_secs: u64,
+    },
+}
+
+impl Verdict {
+    /// Parse the verdict from the server's response message.
+    /// Returns `None` for responses that say nothing about the answer, e.g. when the part is already solved.
+    #[must_use]
+    pub fn from_message(message: &str) -> Option<Self> {
+        if message.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if message.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited {
+                wait_secs: parse_wait(message).unwrap_or(60),
+            })
+        } else if message.contains("answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if message.contains("answer is too low") {
+            Some(Verdict::TooLow)
+        } else if message.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else {
+            None
+        }
+    }
+
+    /// Parse the cooldown in seconds that comes with a wrong answer, e.g. "Please wait one minute before trying
+    /// again". The server refuses every answer until it passes, right or wrong.
+    #[must_use]
+    pub fn cooldown(message: &str) -> Option<u64> {
+        let (before, _) = message.split_once(" before trying again")?;
+        // "Please wait" or "…, please wait"
+        let wait = before.rsplit_once("lease wait ")?.1;
+        let (count, unit) = wait.split_once(' ')?;
+
+        let count: u64 = match count {
+            "one" => 1,
+            "two" => 2,
+            "three" => 3,
+            "four" => 4,
+            "five" => 5,
+            "ten" => 10,
+            count => count.parse().ok()?,
+        };
+        let unit = match unit.trim_end_matches('s') {
+            "second" => 1,
+            "minute" => 60,
+            "hour" => 3600,
+            _ => return None,
+        };
+        Some(count * unit)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait_secs } => write!(f, "rate-limited for {wait_secs}s"),
+        }
+    }
+}
+
+/// Parse the wait time of a rate-limited response, e.g. "You have 1m 2s left to wait".
+fn parse_wait(message: &str) -> Option<u64> {
+    let (before, _) = message.split_once(" left to wait")?;
+    let duration = before.rsplit_once("You have ")?.1;
+
+    duration.split_whitespace().try_fold(0, |total, token| {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        match unit {
+            "h" => Some(total + value * 3600),
+            "m" => Some(total + value * 60),
+            "s" => Some(total + value),
+            _ => None,
+        }
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+    /// Seconds the server refuses further answers for after this one, see [`Verdict::cooldown`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_secs: Option<u64>,
+}
+
+impl Submission {
+    /// Seconds to wait after this submission before answering again, if any.
+    fn wait_secs(&self) -> Option<u64> {
+        match self.verdict {
+            Verdict::RateLimited { wait_secs } => Some(wait_secs),
+            _ => self.cooldown_secs,
+        }
+    }
+}
+
+/// Reason why an answer should not be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved { answer: String },
+    AlreadyRejected { verdict: Verdict },
+    NotBelow { bound: String },
+    NotAbove { bound: String },
+    RateLimited { remaining_secs: u64 },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with \"{answer}\".")
+            }
+            Rejection::AlreadyRejected { verdict } => {
+                write!(f, "this answer was already submitted and was {verdict}.")
+            }
+            Rejection::NotBelow { bound } => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high."
+                )
+            }
+            Rejection::NotAbove { bound } => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low."
+                )
+            }
+            Rejection::RateLimited { remaining_secs } => {
+                write!(f, "rate-limited, wait another {remaining_secs}s.")
+            }
+        }
+    }
+}
+
+/// Submissions of a day, stored in `data/YYYY/submissions/NN.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submissions {
+    #[serde(default, rename = "submission")]
+    pub entries: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Check an answer against the history of a part before submitting it.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), Rejection> {
+        let history = self.entries.iter().filter(|entry| entry.part == part);
+
+        for entry in history.clone() {
+            if let Some(wait_secs) = entry.wait_secs() {
+                let until = entry.submitted_at + wait_secs;
+                if until > now {
+                    return Err(Rejection::RateLimited {
+                        remaining_secs: until - now,
+                    });
+                }
+            }
+
+            match entry.verdict {
+                Verdict::Correct => {
+                    return Err(Rejection::AlreadySolved {
+                        answer: entry.answer.clone(),
+                    });
+                }
+                Verdict::RateLimited { .. } => {}
+                verdict if entry.answer == answer => {
+                    return Err(Rejection::AlreadyRejected { verdict });
+                }
+                _ => {}
+            }
+        }
+
+        // bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let numeric = |verdict: Verdict| {
+            history
+                .clone()
+                .filter(move |entry| entry.verdict == verdict)
+                .filter_map(|entry| entry.answer.parse::<i128>().ok())
+        };
+
+        if let Some(bound) = numeric(Verdict::TooHigh).min() {
+            if value >= bound {
+                return Err(Rejection::NotBelow {
+                    bound: bound.to_string(),
+                });
+            }
+        }
+
+        if let Some(bound) = numeric(Verdict::TooLow).max() {
+            if value <= bound {
+                return Err(Rejection::NotAbove {
+                    bound: bound.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict, now: u64) {
+        self.record_with_cooldown(part, answer, verdict, None, now);
+    }
+
+    /// Record a submission together with the cooldown the server imposed after it.
+    pub fn record_with_cooldown(
+        &mut self,
+        part: u8,
+        answer: &str,
+        verdict: Verdict,
+        cooldown_secs: Option<u64>,
+        now: u64,
+    ) {
+        self.entries.push(Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            submitted_at: now,
+            cooldown_secs,
+        });
+    }
+
+    fn from_toml(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    fn to_toml(&self) -> String {
+        toml::to_string(self).expect("submissions are always serializable")
+    }
+}
+
+/// Seconds since the unix epoch.
+#[must_use]
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn get_path(year: u16, day: u8) -> PathBuf {
+    get_data_path("submissions", year, day, "toml")
+}
+
+/// Load the submissions of a day. Days without a submissions file have no history.
+pub fn load(year: u16, day: u8) -> Result<Submissions, Error> {
+    match fs::read_to_string(get_path(year, day)) {
+        Ok(s) => Submissions::from_toml(&s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save(year: u16, day: u8, submissions: &Submissions) -> Result<(), Error> {
+    let path = get_path(year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, submissions.to_toml())?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Rejection, Submissions, Verdict};
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::from_message("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too high. Please wait one minute before trying again."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer. If you're stuck, make sure you're using the full input data."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_message(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_cooldowns() {
+        let wrong = "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the about page, or you can ask for hints on the subreddit.  Please wait one minute before trying again. [Return to Day 1]";
+        assert_eq!(Verdict::from_message(wrong), Some(Verdict::TooHigh));
+        assert_eq!(Verdict::cooldown(wrong), Some(60));
+
+        let repeated = "That's not the right answer.  If you're stuck, make sure you're using the full input data; there are also some general tips on the about page, or you can ask for hints on the subreddit.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again. [Return to Day 1]";
+        assert_eq!(Verdict::from_message(repeated), Some(Verdict::Wrong));
+        assert_eq!(Verdict::cooldown(repeated), Some(300));
+
+        assert_eq!(
+            Verdict::cooldown("That's the right answer! You are one gold star closer."),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        assert_eq!(
+            Verdict::from_message("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 2s left to wait."),
+            Some(Verdict::RateLimited { wait_secs: 62 })
+        );
+        assert_eq!(
+            Verdict::from_message("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait."),
+            Some(Verdict::RateLimited { wait_secs: 34 })
+        );
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, "abc", Verdict::Wrong, 0);
+
+        assert_eq!(
+            submissions.check(1, "abc", 100),
+            Err(Rejection::AlreadyRejected {
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(submissions.check(1, "abd", 100), Ok(()));
+        assert_eq!(submissions.check(2, "abc", 100), Ok(()));
+
+        submissions.record(1, "abd", Verdict::Correct, 100);
+        assert_eq!(
+            submissions.check(1, "abe", 200),
+            Err(Rejection::AlreadySolved {
+                answer: "abd".into()
+            })
+        );
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, "100", Verdict::TooHigh, 0);
+        submissions.record(1, "200", Verdict::TooHigh, 0);
+        submissions.record(1, "10", Verdict::TooLow, 0);
+
+        assert_eq!(
+            submissions.check(1, "150", 100),
+            Err(Rejection::NotBelow {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            submissions.check(1, "10", 100),
+            Err(Rejection::AlreadyRejected {
+                verdict: Verdict::TooLow
+            })
+        );
+        assert_eq!(
+            submissions.check(1, "5", 100),
+            Err(Rejection::NotAbove { bound: "10".into() })
+        );
+        assert_eq!(submissions.check(1, "50", 100), Ok(()));
+    }
+
+    #[test]
+    fn refuses_while_rate_limited() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, "42", Verdict::RateLimited { wait_secs: 60 }, 1000);
+
+        assert_eq!(
+            submissions.check(1, "43", 1030),
+            Err(Rejection::RateLimited { remaining_secs: 30 })
+        );
+        assert_eq!(submissions.check(1, "42", 1060), Ok(()));
+
+        // a wrong answer with a cooldown is a single entry that both rejects and rate-limits
+        submissions.record_with_cooldown(1, "100", Verdict::TooHigh, Some(60), 2000);
+        assert_eq!(submissions.entries.len(), 2);
+        assert_eq!(
+            submissions.check(1, "50", 2030),
+            Err(Rejection::RateLimited { remaining_secs: 30 })
+        );
+        assert_eq!(submissions.check(1, "50", 2060), Ok(()));
+        assert_eq!(
+            submissions.check(1, "100", 2060),
+            Err(Rejection::AlreadyRejected {
+                verdict: Verdict::TooHigh
+            })
+        );
+    }
+
+    #[test]
+    fn roundtrips_toml() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, "42", Verdict::TooLow, 1);
+        submissions.record(2, "7", Verdict::RateLimited { wait_secs: 30 }, 2);
+        submissions.record_with_cooldown(2, "8", Verdict::Wrong, Some(60), 3);
+
+        assert_eq!(
+            Submissions::from_toml(&submissions.to_toml()).unwrap(),
+            submissions
+        );
+    }
+}
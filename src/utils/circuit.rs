@@ -0,0 +1,780 @@
+/// Pulse propagation circuits of flip-flops and conjunctions: simulation, sub-counter analysis and DOT export.
+use std::collections::VecDeque;
+use std::fmt::{self, Write};
+
+use fxhash::FxHashMap;
+use itertools::Itertools;
+
+use super::{
+    cycle::Cycle,
+    graph::Interner,
+    number_theory::{crt, lcm},
+    trace,
+};
+use crate::template::error::{split_once_at, Error, Result};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+impl fmt::Display for Pulse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Pulse::High => "high",
+            Pulse::Low => "low",
+        })
+    }
+}
+
+/// Numbers of low and high pulses.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Pulses {
+    pub low: u64,
+    pub high: u64,
+}
+
+impl Pulses {
+    #[must_use]
+    pub fn get(&self, pulse: Pulse) -> u64 {
+        match pulse {
+            Pulse::Low => self.low,
+            Pulse::High => self.high,
+        }
+    }
+
+    fn add(&mut self, pulse: Pulse) {
+        match pulse {
+            Pulse::Low => self.low += 1,
+            Pulse::High => self.high += 1,
+        }
+    }
+
+    fn since(&self, earlier: Pulses) -> Pulses {
+        Pulses {
+            low: self.low - earlier.low,
+            high: self.high - earlier.high,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Kind {
+    /// Sends a low pulse to the broadcaster when pressed.
+    Button,
+    /// Sends every pulse it receives to all of its destinations.
+    Broadcaster,
+    /// `%`: ignores high pulses and toggles on low ones, sending high when it turns on and low when it turns off.
+    FlipFlop,
+    /// `&`: remembers the last pulse from each input and sends low if they were all high, high otherwise.
+    Conjunction,
+    /// Modules that are only named as a destination, like `rx`. They receive pulses but never send any.
+    Output,
+}
+
+#[derive(Debug, Clone)]
+pub struct Module<'a> {
+    name: &'a str,
+    kind: Kind,
+    /// Receiving modules, with the position of this module among their inputs.
+    destinations: Vec<(usize, usize)>,
+    inputs: Vec<usize>,
+}
+
+impl<'a> Module<'a> {
+    #[must_use]
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    pub fn destinations(&self) -> impl Iterator<Item = usize> + '_ {
+        self.destinations.iter().map(|&(to, _)| to)
+    }
+
+    #[must_use]
+    pub fn inputs(&self) -> &[usize] {
+        &self.inputs
+    }
+}
+
+/// A pulse on its way from one module to another, by index.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Signal {
+    pub from: usize,
+    pub to: usize,
+    pub pulse: Pulse,
+}
+
+/// The modules and their connections. The button is module [`Circuit::BUTTON`], wired to the broadcaster if there is
+/// one. Run it with a [`Simulation`].
+#[derive(Debug, Clone)]
+pub struct Circuit<'a> {
+    modules: Vec<Module<'a>>,
+    names: Interner<&'a str>,
+    broadcaster: Option<usize>,
+}
+
+/// Presses after which [`Circuit::first_low_pulse`] gives up on sub-counters repeating.
+const MAX_PRESSES: u64 = 1 << 16;
+
+/// Presses a [`PressSet`] may list in a period before it is too irregular to combine.
+const MAX_RESIDUES: usize = 1 << 16;
+
+impl<'a> Circuit<'a> {
+    pub const BUTTON: usize = 0;
+
+    pub fn parse(input: &'a str) -> Result<Self> {
+        let mut names = Interner::new();
+        names.intern("button");
+        let mut modules = vec![Module {
+            name: "button",
+            kind: Kind::Button,
+            destinations: Vec::new(),
+            inputs: Vec::new(),
+        }];
+
+        let mut wiring = Vec::new();
+        for line in input.trim_end().lines() {
+            let (module, outputs) = split_once_at(input, line, " -> ")?;
+            let (kind, name) = match module.as_bytes().first() {
+                Some(b'&') => (Kind::Conjunction, &module[1..]),
+                Some(b'%') => (Kind::FlipFlop, &module[1..]),
+                _ if module == "broadcaster" => (Kind::Broadcaster, module),
+                _ => {
+                    return Err(Error::at(
+                        input,
+                        module,
+                        format!("invalid module \"{module}\", expected \"broadcaster\" or a name prefixed with '%' or '&'"),
+                    ))
+                }
+            };
+            if names.get(&name).is_some() {
+                return Err(Error::at(
+                    input,
+                    name,
+                    format!("module \"{name}\" is defined twice"),
+                ));
+            }
+
+            modules.push(Module {
+                name,
+                kind,
+                destinations: Vec::new(),
+                inputs: Vec::new(),
+            });
+            wiring.push((names.intern(name), outputs));
+        }
+
+        let broadcaster = names.get(&"broadcaster");
+        wiring.extend(broadcaster.map(|_| (Self::BUTTON, "broadcaster")));
+        for (from, outputs) in wiring {
+            for name in outputs.split(", ") {
+                let to = names.intern(name);
+                if to == modules.len() {
+                    modules.push(Module {
+                        name,
+                        kind: Kind::Output,
+                        destinations: Vec::new(),
+                        inputs: Vec::new(),
+                    });
+                }
+                let slot = modules[to].inputs.len();
+                modules[to].inputs.push(from);
+                modules[from].destinations.push((to, slot));
+            }
+        }
+
+        Ok(Circuit {
+            modules,
+            names,
+            broadcaster,
+        })
+    }
+
+    #[must_use]
+    pub fn modules(&self) -> &[Module<'a>] {
+        &self.modules
+    }
+
+    #[must_use]
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.names.get(&name)
+    }
+
+    #[must_use]
+    pub fn name(&self, module: usize) -> &'a str {
+        self.modules[module].name
+    }
+
+    #[must_use]
+    pub fn simulate(&self) -> Simulation<'_, 'a> {
+        Simulation::new(self)
+    }
+
+    /// Modules reachable from `start` without going through the button or the broadcaster.
+    fn reachable(&self, start: usize) -> Vec<bool> {
+        let mut reached = vec![false; self.modules.len()];
+        let mut queue = vec![start];
+        reached[start] = true;
+        while let Some(module) = queue.pop() {
+            for to in self.modules[module].destinations() {
+                if !reached[to] && Some(to) != self.broadcaster {
+                    reached[to] = true;
+                    queue.push(to);
+                }
+            }
+        }
+        reached
+    }
+
+    /// Groups of modules that only the button drives, like the binary counters real inputs are made of.
+    ///
+    /// Each broadcaster destination starts a sub-counter, and destinations that reach a common flip-flop start the
+    /// same one. A sub-counter has the modules that only its destinations reach. Groups with inputs from elsewhere
+    /// are left out, since their behaviour depends on more than the number of presses.
+    #[must_use]
+    pub fn sub_counters(&self) -> Vec<Vec<usize>> {
+        let Some(broadcaster) = self.broadcaster else {
+            return Vec::new();
+        };
+        let starts: Vec<usize> = self.modules[broadcaster].destinations().unique().collect();
+        let reach: Vec<Vec<bool>> = starts.iter().map(|&start| self.reachable(start)).collect();
+
+        let mut group: Vec<usize> = (0..starts.len()).collect();
+        for (module, _) in self
+            .modules
+            .iter()
+            .enumerate()
+            .filter(|(_, module)| module.kind == Kind::FlipFlop)
+        {
+            let reaching: Vec<usize> = (0..starts.len()).filter(|&s| reach[s][module]).collect();
+            if let Some(&first) = reaching.iter().map(|&s| &group[s]).min() {
+                let merged: Vec<usize> = reaching.iter().map(|&s| group[s]).collect();
+                for g in &mut group {
+                    if merged.contains(g) {
+                        *g = first;
+                    }
+                }
+            }
+        }
+
+        let mut counters: Vec<Vec<usize>> = vec![Vec::new(); starts.len()];
+        for (module, _) in self
+            .modules
+            .iter()
+            .enumerate()
+            .filter(|(_, module)| matches!(module.kind, Kind::FlipFlop | Kind::Conjunction))
+        {
+            let groups: Vec<usize> = (0..starts.len())
+                .filter(|&s| reach[s][module])
+                .map(|s| group[s])
+                .unique()
+                .collect();
+            if let [group] = groups[..] {
+                counters[group].push(module);
+            }
+        }
+
+        counters.retain(|modules| {
+            !modules.is_empty()
+                && modules.iter().all(|&module| {
+                    self.modules[module]
+                        .inputs
+                        .iter()
+                        .all(|input| *input == broadcaster || modules.contains(input))
+                })
+        });
+        counters
+    }
+
+    /// Number of presses until `target` first receives a low pulse, if it ever does.
+    ///
+    /// Presses the button until it does, or until every sub-counter is back in a state it was in before. The presses
+    /// on which each sub-counter sends low and high pulses out repeat from then on, and the modules between the
+    /// sub-counters and `target` say which combinations of them get a low pulse through. Those modules have to be
+    /// conjunctions that see the pulses of one press together, which is how real inputs combine their counters.
+    pub fn first_low_pulse(&self, target: usize) -> Result<Option<u64>> {
+        let counters = self.sub_counters();
+        let mut owner = vec![None; self.modules.len()];
+        for (counter, modules) in counters.iter().enumerate() {
+            for &module in modules {
+                owner[module] = Some(counter);
+            }
+        }
+        // modules with destinations outside their sub-counter, and what they sent out on every press
+        let exits: Vec<usize> = (0..self.modules.len())
+            .filter(|&module| {
+                owner[module].is_some()
+                    && self.modules[module]
+                        .destinations()
+                        .any(|to| owner[to] != owner[module])
+            })
+            .collect();
+        let mut sent: FxHashMap<usize, Vec<Pulses>> =
+            exits.iter().map(|&module| (module, Vec::new())).collect();
+
+        let mut simulation = self.simulate();
+        let mut seen: Vec<FxHashMap<Vec<bool>, u64>> = counters
+            .iter()
+            .map(|modules| [(simulation.snapshot(modules), 0)].into_iter().collect())
+            .collect();
+        let mut cycles: Vec<Option<Cycle>> = vec![None; counters.len()];
+
+        while simulation.presses() < MAX_PRESSES {
+            let received = simulation.received(target);
+            let before: Vec<Pulses> = exits
+                .iter()
+                .map(|&module| simulation.sent(module))
+                .collect();
+            simulation.press();
+
+            if simulation.received(target).low > received.low {
+                return Ok(Some(simulation.presses()));
+            }
+            for (module, before) in exits.iter().zip(before) {
+                let pulses = simulation.sent(*module).since(before);
+                sent.get_mut(module).unwrap().push(pulses);
+            }
+
+            for (counter, modules) in counters.iter().enumerate() {
+                if cycles[counter].is_some() {
+                    continue;
+                }
+                let state = simulation.snapshot(modules);
+                match seen[counter].get(&state) {
+                    Some(&start) => {
+                        cycles[counter] = Some(Cycle {
+                            start: start as usize,
+                            length: (simulation.presses() - start) as usize,
+                        })
+                    }
+                    None => {
+                        seen[counter].insert(state, simulation.presses());
+                    }
+                }
+            }
+            if !counters.is_empty() && cycles.iter().all(Option::is_some) {
+                break;
+            }
+        }
+
+        if counters.is_empty() || cycles.iter().any(Option::is_none) {
+            return Err(Error::solver(format!(
+                "\"{}\" did not receive a low pulse in {MAX_PRESSES} presses and the circuit has no sub-counters that repeat before",
+                self.name(target)
+            )));
+        }
+
+        let analysis = Analysis {
+            circuit: self,
+            owner,
+            cycles: cycles.into_iter().flatten().collect(),
+            sent,
+        };
+        // every sub-counter repeats by now, before it could still be on its way into the cycle
+        let mut first = None;
+        for &input in &self.modules[target].inputs {
+            let presses = analysis.presses(input, Pulse::Low, &mut Vec::new())?;
+            first = first
+                .into_iter()
+                .chain(presses.first_after(simulation.presses()))
+                .min();
+        }
+        Ok(first)
+    }
+
+    /// The module graph in [Graphviz](https://graphviz.org) DOT, with every sub-counter in a cluster of its own.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let node = |module: usize| {
+            let Module { name, kind, .. } = self.modules[module];
+            let (label, shape) = match kind {
+                Kind::Button => (name.to_string(), "circle"),
+                Kind::Broadcaster => (name.to_string(), "doublecircle"),
+                Kind::FlipFlop => (format!("%{name}"), "box"),
+                Kind::Conjunction => (format!("&{name}"), "invtrapezium"),
+                Kind::Output => (name.to_string(), "plaintext"),
+            };
+            format!("\"{name}\" [label=\"{label}\", shape={shape}];")
+        };
+
+        let counters = self.sub_counters();
+        let mut dot = String::from("digraph circuit {\n");
+        for (i, modules) in counters.iter().enumerate() {
+            writeln!(dot, "    subgraph cluster_{i} {{").unwrap();
+            writeln!(dot, "        label=\"sub-counter {}\";", i + 1).unwrap();
+            for &module in modules {
+                writeln!(dot, "        {}", node(module)).unwrap();
+            }
+            dot += "    }\n";
+        }
+        for module in 0..self.modules.len() {
+            if !counters.iter().any(|modules| modules.contains(&module)) {
+                writeln!(dot, "    {}", node(module)).unwrap();
+            }
+        }
+        for module in &self.modules {
+            for to in module.destinations() {
+                writeln!(dot, "    \"{}\" -> \"{}\";", module.name, self.name(to)).unwrap();
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+}
+
+/// The state of a [`Circuit`] over button presses, with the pulses every module sent and received so far.
+#[derive(Debug, Clone)]
+pub struct Simulation<'c, 'a> {
+    circuit: &'c Circuit<'a>,
+    /// Whether each flip-flop is on.
+    on: Vec<bool>,
+    /// The last pulse each conjunction received from each of its inputs, and how many of them were high.
+    memory: Vec<Vec<Pulse>>,
+    high_inputs: Vec<usize>,
+    sent: Vec<Pulses>,
+    received: Vec<Pulses>,
+    presses: u64,
+    queue: VecDeque<(Signal, usize)>,
+}
+
+impl<'c, 'a> Simulation<'c, 'a> {
+    #[must_use]
+    pub fn new(circuit: &'c Circuit<'a>) -> Self {
+        let modules = circuit.modules.len();
+        Simulation {
+            circuit,
+            on: vec![false; modules],
+            memory: circuit
+                .modules
+                .iter()
+                .map(|module| vec![Pulse::Low; module.inputs.len()])
+                .collect(),
+            high_inputs: vec![0; modules],
+            sent: vec![Pulses::default(); modules],
+            received: vec![Pulses::default(); modules],
+            presses: 0,
+            queue: VecDeque::new(),
+        }
+    }
+
+    pub fn press(&mut self) {
+        self.press_with(|_| ());
+    }
+
+    /// Press the button and pass every pulse to `observe` in the order they are sent.
+    pub fn press_with(&mut self, mut observe: impl FnMut(&Signal)) {
+        self.presses += 1;
+        trace::event(|| format!("press {}", self.presses));
+
+        self.send(Circuit::BUTTON, Pulse::Low);
+        while let Some((signal, slot)) = self.queue.pop_front() {
+            observe(&signal);
+            trace::event(|| {
+                format!(
+                    "{} -{}-> {}",
+                    self.circuit.name(signal.from),
+                    signal.pulse,
+                    self.circuit.name(signal.to)
+                )
+            });
+            self.received[signal.to].add(signal.pulse);
+
+            let to = signal.to;
+            match self.circuit.modules[to].kind {
+                Kind::Broadcaster => self.send(to, signal.pulse),
+                Kind::FlipFlop if signal.pulse == Pulse::Low => {
+                    self.on[to] = !self.on[to];
+                    self.send(to, if self.on[to] { Pulse::High } else { Pulse::Low });
+                }
+                Kind::Conjunction => {
+                    let last = std::mem::replace(&mut self.memory[to][slot], signal.pulse);
+                    match (last, signal.pulse) {
+                        (Pulse::Low, Pulse::High) => self.high_inputs[to] += 1,
+                        (Pulse::High, Pulse::Low) => self.high_inputs[to] -= 1,
+                        _ => (),
+                    }
+                    let all_high = self.high_inputs[to] == self.memory[to].len();
+                    self.send(to, if all_high { Pulse::Low } else { Pulse::High });
+                }
+                Kind::Button | Kind::FlipFlop | Kind::Output => (),
+            }
+        }
+    }
+
+    fn send(&mut self, from: usize, pulse: Pulse) {
+        for &(to, slot) in &self.circuit.modules[from].destinations {
+            self.sent[from].add(pulse);
+            self.queue.push_back((Signal { from, to, pulse }, slot));
+        }
+    }
+
+    #[must_use]
+    pub fn presses(&self) -> u64 {
+        self.presses
+    }
+
+    #[must_use]
+    pub fn sent(&self, module: usize) -> Pulses {
+        self.sent[module]
+    }
+
+    #[must_use]
+    pub fn received(&self, module: usize) -> Pulses {
+        self.received[module]
+    }
+
+    /// All pulses sent so far, the button's included.
+    #[must_use]
+    pub fn total(&self) -> Pulses {
+        self.sent
+            .iter()
+            .fold(Pulses::default(), |total, sent| Pulses {
+                low: total.low + sent.low,
+                high: total.high + sent.high,
+            })
+    }
+
+    /// The flip-flop states and conjunction memories of `modules`, to compare states between presses.
+    #[must_use]
+    pub fn snapshot(&self, modules: &[usize]) -> Vec<bool> {
+        modules
+            .iter()
+            .flat_map(|&module| {
+                let memory = self.memory[module]
+                    .iter()
+                    .map(|&pulse| pulse == Pulse::High);
+                std::iter::once(self.on[module]).chain(memory)
+            })
+            .collect()
+    }
+}
+
+/// The presses `n ≥ 1` with `n % period` in `residues`.
+#[derive(Debug, Clone)]
+struct PressSet {
+    period: u64,
+    residues: Vec<u64>,
+}
+
+impl PressSet {
+    fn every() -> Self {
+        PressSet {
+            period: 1,
+            residues: vec![0],
+        }
+    }
+
+    fn never() -> Self {
+        PressSet {
+            period: 1,
+            residues: Vec::new(),
+        }
+    }
+
+    /// The first press after `press` in the set.
+    fn first_after(&self, press: u64) -> Option<u64> {
+        let base = press - press % self.period;
+        self.residues
+            .iter()
+            .map(|&residue| base + residue)
+            .map(|n| if n <= press { n + self.period } else { n })
+            .min()
+    }
+
+    fn common_period(&self, other: &PressSet) -> Result<u64> {
+        lcm(self.period, other.period).ok_or_else(|| Error::solver("number of presses overflows"))
+    }
+
+    fn intersect(&self, other: &PressSet) -> Result<PressSet> {
+        let period = self.common_period(other)?;
+        if self.residues.len() * other.residues.len() > MAX_RESIDUES {
+            return Err(Error::solver(
+                "sub-counters send pulses too irregularly to combine",
+            ));
+        }
+
+        let residues = self
+            .residues
+            .iter()
+            .cartesian_product(&other.residues)
+            .filter_map(|(&a, &b)| {
+                let congruences = [(a, self.period), (b, other.period)];
+                crt(congruences
+                    .map(|(residue, modulus)| (i128::from(residue), i128::from(modulus))))
+            })
+            .map(|(residue, _)| residue as u64)
+            .sorted()
+            .collect();
+        Ok(PressSet { period, residues })
+    }
+
+    fn union(&self, other: &PressSet) -> Result<PressSet> {
+        let period = self.common_period(other)?;
+        let expand = |set: &PressSet| -> Vec<u64> {
+            (0..period / set.period)
+                .flat_map(|k| set.residues.iter().map(move |r| r + k * set.period))
+                .collect()
+        };
+        let count = (period / self.period) as usize * self.residues.len()
+            + (period / other.period) as usize * other.residues.len();
+        if count > MAX_RESIDUES {
+            return Err(Error::solver(
+                "sub-counters send pulses too irregularly to combine",
+            ));
+        }
+
+        let residues = expand(self)
+            .into_iter()
+            .chain(expand(other))
+            .sorted()
+            .dedup()
+            .collect();
+        Ok(PressSet { period, residues })
+    }
+}
+
+/// What [`Circuit::first_low_pulse`] learned about the sub-counters of a circuit.
+struct Analysis<'c, 'a> {
+    circuit: &'c Circuit<'a>,
+    owner: Vec<Option<usize>>,
+    cycles: Vec<Cycle>,
+    /// Pulses each module that feeds something outside its sub-counter sent in each press, until its sub-counter
+    /// repeated.
+    sent: FxHashMap<usize, Vec<Pulses>>,
+}
+
+impl Analysis<'_, '_> {
+    /// The presses on which `module` sends `pulse`. `visiting` holds the modules this one is an input of.
+    fn presses(&self, module: usize, pulse: Pulse, visiting: &mut Vec<usize>) -> Result<PressSet> {
+        if let Some(counter) = self.owner[module] {
+            // the state after `start` presses comes back after `length` more
+            let Cycle { start, length } = self.cycles[counter];
+            let period = length as u64;
+            let residues = self.sent[&module][start..start + length]
+                .iter()
+                .zip(start as u64 + 1..)
+                .filter(|(pulses, _)| pulses.get(pulse) > 0)
+                .map(|(_, press)| press % period)
+                .sorted()
+                .collect();
+            return Ok(PressSet { period, residues });
+        }
+
+        let name = self.circuit.name(module);
+        if visiting.contains(&module) {
+            return Err(Error::solver(format!(
+                "the modules after the sub-counters loop back at \"{name}\""
+            )));
+        }
+
+        let inputs = &self.circuit.modules[module].inputs;
+        visiting.push(module);
+        let presses = match (self.circuit.modules[module].kind, &inputs[..]) {
+            (Kind::Button | Kind::Output, _) => Ok(PressSet::never()),
+            (Kind::Broadcaster, _) if pulse == Pulse::Low => Ok(PressSet::every()),
+            (Kind::Broadcaster, _) => Ok(PressSet::never()),
+            // an inverter
+            (Kind::Conjunction, &[input]) => {
+                let inverted = match pulse {
+                    Pulse::Low => Pulse::High,
+                    Pulse::High => Pulse::Low,
+                };
+                self.presses(input, inverted, visiting)
+            }
+            // low once every input sent high in the same press, high whenever an input sends anything
+            (Kind::Conjunction, _) if pulse == Pulse::Low => inputs
+                .iter()
+                .try_fold(PressSet::every(), |presses, &input| {
+                    presses.intersect(&self.presses(input, Pulse::High, visiting)?)
+                }),
+            (Kind::Conjunction, _) => {
+                inputs
+                    .iter()
+                    .try_fold(PressSet::never(), |presses, &input| {
+                        let low = self.presses(input, Pulse::Low, visiting)?;
+                        let high = self.presses(input, Pulse::High, visiting)?;
+                        presses.union(&low)?.union(&high)
+                    })
+            }
+            (Kind::FlipFlop, _) => Err(Error::solver(format!(
+                "flip-flop \"{name}\" after the sub-counters depends on more than the current press"
+            ))),
+        };
+        visiting.pop();
+        presses
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    /// Counters of 5 and 7 presses, whose inverters feed `rx` through a conjunction.
+    const COUNTERS: &str = "\
+broadcaster -> a0, b0
+%a0 -> a1, ca
+%a1 -> a2
+%a2 -> ca
+&ca -> a1, a0, ia
+&ia -> rx_feed
+%b0 -> b1, cb
+%b1 -> b2, cb
+%b2 -> cb
+&cb -> b0, ib
+&ib -> rx_feed
+&rx_feed -> rx
+";
+
+    #[test]
+    fn finds_sub_counters() {
+        let circuit = Circuit::parse(COUNTERS).unwrap();
+        let names = |modules: &Vec<usize>| modules.iter().map(|&m| circuit.name(m)).join(" ");
+        let counters: Vec<_> = circuit.sub_counters().iter().map(names).collect();
+        assert_eq!(counters, ["a0 a1 a2 ca ia", "b0 b1 b2 cb ib"]);
+        let rx = circuit.index("rx").unwrap();
+        assert_eq!(circuit.first_low_pulse(rx), Ok(Some(35)));
+    }
+
+    #[test]
+    fn simulates_presses() {
+        let circuit = Circuit::parse(COUNTERS).unwrap();
+        let mut simulation = circuit.simulate();
+        let mut signals = Vec::new();
+        simulation.press_with(|signal| signals.push(*signal));
+
+        let broadcaster = circuit.index("broadcaster").unwrap();
+        assert_eq!(
+            signals[0],
+            Signal {
+                from: Circuit::BUTTON,
+                to: broadcaster,
+                pulse: Pulse::Low
+            }
+        );
+        assert_eq!(simulation.presses(), 1);
+        assert_eq!(simulation.sent(broadcaster), Pulses { low: 2, high: 0 });
+        assert_eq!(simulation.received(broadcaster), Pulses { low: 1, high: 0 });
+        assert_eq!(
+            simulation.total().low + simulation.total().high,
+            signals.len() as u64
+        );
+    }
+
+    #[test]
+    fn exports_dot() {
+        let circuit = Circuit::parse("broadcaster -> a\n%a -> inv\n&inv -> rx\n").unwrap();
+        let dot = circuit.to_dot();
+        assert!(dot.starts_with("digraph circuit {\n    subgraph cluster_0 {\n"));
+        assert!(dot.contains("        \"a\" [label=\"%a\", shape=box];\n"));
+        assert!(dot.contains("    \"rx\" [label=\"rx\", shape=plaintext];\n"));
+        assert!(dot.contains("    \"button\" -> \"broadcaster\";\n"));
+        assert!(dot.contains("    \"inv\" -> \"rx\";\n"));
+    }
+}
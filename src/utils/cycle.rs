@@ -0,0 +1,170 @@
+/// Cycle detection for simulations that eventually repeat a state, like "what does it look like after a billion steps?".
+/// All functions iterate `state, step(state), step(step(state)), …` and loop forever if no state repeats.
+use std::hash::Hash;
+
+use fxhash::FxHashMap;
+
+/// States repeat from step `start` on, every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step, before the cycle repeats, whose state equals the state after `n` steps.
+    #[must_use]
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Find the cycle by remembering every state. Also returns the states up to the first repetition,
+/// so `history[cycle.index(n)]` is the state after `n` steps.
+///
+/// States are only kept once and never cloned, so `step` is the only place that allocates.
+pub fn find_cycle<T: Hash + Eq>(initial: T, step: impl FnMut(&T) -> T) -> (Cycle, Vec<T>) {
+    let (cycle, history) = simulate(initial, step, usize::MAX);
+    (cycle.expect("no state repeated"), history)
+}
+
+/// The state after `n` steps, skipping ahead as soon as a cycle shows up.
+pub fn state_after<T: Hash + Eq>(initial: T, step: impl FnMut(&T) -> T, n: usize) -> T {
+    let (cycle, mut history) = simulate(initial, step, n);
+    let index = cycle.map_or(n, |cycle| cycle.index(n));
+    history.swap_remove(index)
+}
+
+/// Remember states until one repeats or `n` steps were done. The history holds `n + 1` states in the latter case.
+fn simulate<T: Hash + Eq>(
+    initial: T,
+    mut step: impl FnMut(&T) -> T,
+    n: usize,
+) -> (Option<Cycle>, Vec<T>) {
+    // states are looked up by hash, so that they do not have to be stored twice
+    let mut seen: FxHashMap<u64, Vec<usize>> = FxHashMap::default();
+    let mut history = vec![initial];
+    seen.insert(fxhash::hash64(&history[0]), vec![0]);
+
+    while history.len() <= n {
+        let state = step(&history[history.len() - 1]);
+        let indices = seen.entry(fxhash::hash64(&state)).or_default();
+
+        if let Some(&start) = indices.iter().find(|&&i| history[i] == state) {
+            let cycle = Cycle {
+                start,
+                length: history.len() - start,
+            };
+            return (Some(cycle), history);
+        }
+
+        indices.push(history.len());
+        history.push(state);
+    }
+
+    (None, history)
+}
+
+/// Brent's algorithm: finds the cycle with only two states in memory, at the cost of running `step` more often.
+/// Use it when states are large and `step` is cheap.
+pub fn brent<T: Eq>(initial: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // the tortoise waits at checkpoints while the hare runs ahead in growing powers of two, `None` is the initial state
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise: Option<T> = None;
+    let mut hare = step(&initial);
+
+    while *tortoise.as_ref().unwrap_or(&initial) != hare {
+        let next = step(&hare);
+        if power == length {
+            tortoise = Some(std::mem::replace(&mut hare, next));
+            power *= 2;
+            length = 0;
+        } else {
+            hare = next;
+        }
+        length += 1;
+    }
+
+    // walk from the start with the hare `length` steps ahead, they meet where the cycle begins
+    let mut hare = step(&initial);
+    for _ in 1..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    let mut tortoise: Option<T> = None;
+    while *tortoise.as_ref().unwrap_or(&initial) != hare {
+        tortoise = Some(step(tortoise.as_ref().unwrap_or(&initial)));
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, find_cycle, state_after, Cycle};
+
+    /// 0, 1, 2, 3, 4, 5, 2, 3, 4, 5, …
+    fn step(&x: &u32) -> u32 {
+        if x < 5 {
+            x + 1
+        } else {
+            2
+        }
+    }
+
+    const CYCLE: Cycle = Cycle {
+        start: 2,
+        length: 4,
+    };
+
+    #[test]
+    fn finds_cycle_with_history() {
+        let (cycle, history) = find_cycle(0, step);
+        assert_eq!(cycle, CYCLE);
+        assert_eq!(history, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn finds_cycle_with_brent() {
+        assert_eq!(brent(0, step), CYCLE);
+        assert_eq!(
+            brent(3, step),
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(
+            brent(7, |_| 7),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn maps_steps_into_cycle() {
+        assert_eq!(CYCLE.index(1), 1);
+        assert_eq!(CYCLE.index(6), 2);
+        assert_eq!(CYCLE.index(1_000_000_001), 5);
+    }
+
+    #[test]
+    fn skips_to_state() {
+        assert_eq!(state_after(0, step, 0), 0);
+        assert_eq!(state_after(0, step, 4), 4);
+        assert_eq!(state_after(0, step, 6), 2);
+        assert_eq!(state_after(0, step, 1_000_000_000), 4);
+        // stops before the cycle shows up
+        assert_eq!(state_after(0, |&x| x + 1, 10), 10);
+    }
+}
@@ -0,0 +1,441 @@
+/// Integer points and directions on the puzzle plane.
+/// Screen coordinates are used throughout: `x` grows to the right and `y` grows downwards, so `Up` is `(0, -1)`.
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position or offset on a 2D plane.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Taxicab distance, the number of orthogonal steps between two points.
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The point one step in `direction`.
+    #[must_use]
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The four orthogonal neighbours: up, right, down, left.
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// All eight neighbours, clockwise starting from the top.
+    pub fn neighbours_diagonal(self) -> impl Iterator<Item = Point> {
+        Direction8::ALL.into_iter().map(move |d| self + d.offset())
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    /// Convert a position of a [`Grid`](super::Grid).
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A position or offset in 3D space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The six neighbours sharing a face with this point.
+    pub fn neighbours(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Component-wise arithmetic, shared by both point types.
+macro_rules! impl_arithmetic {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, rhs: i64) -> Self::Output {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_arithmetic!(Point { x, y });
+impl_arithmetic!(Point3 { x, y, z });
+
+/// One of the four cardinal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The unit step in this direction.
+    #[must_use]
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// Rotate by 90° counter-clockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// Rotate by 90° clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// The direction of a single step from `from` to `to`, if they are orthogonal neighbours.
+    #[must_use]
+    pub fn between(from: Point, to: Point) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == to - from)
+    }
+
+    #[must_use]
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    #[must_use]
+    pub const fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    /// Parse an arrow as drawn on puzzle maps: `^`, `>`, `v` or `<`.
+    #[must_use]
+    pub const fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// Parse the initial of a direction: `U`, `R`, `D`, `L` or the compass points `N`, `E`, `S`, `W`.
+    #[must_use]
+    pub const fn from_letter(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' => Some(Direction::Up),
+            'R' | 'E' => Some(Direction::Right),
+            'D' | 'S' => Some(Direction::Down),
+            'L' | 'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn to_arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// One of the eight cardinal and diagonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The unit step in this direction. Diagonal steps change both coordinates.
+    #[must_use]
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction8::Up => Point::new(0, -1),
+            Direction8::UpRight => Point::new(1, -1),
+            Direction8::Right => Point::new(1, 0),
+            Direction8::DownRight => Point::new(1, 1),
+            Direction8::Down => Point::new(0, 1),
+            Direction8::DownLeft => Point::new(-1, 1),
+            Direction8::Left => Point::new(-1, 0),
+            Direction8::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotate by 45° counter-clockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Rotate by 45° clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        self.offset().x != 0 && self.offset().y != 0
+    }
+
+    /// Rotate clockwise by `steps` of 45°.
+    fn rotate(self, steps: usize) -> Self {
+        Self::ALL[(self as usize + steps) % Self::ALL.len()]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Direction8, Point, Point3};
+
+    #[test]
+    fn does_arithmetic() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a + b, Point::new(-2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 3, Point::new(3, -6));
+        assert_eq!(-a, Point::new(-1, 2));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1) * 2,
+            Point3::new(3, 4, 5)
+        );
+    }
+
+    #[test]
+    fn measures_manhattan_distance() {
+        assert_eq!(Point::new(1, 6).manhattan(Point::new(5, 11)), 9);
+        assert_eq!(Point::new(-3, 0).manhattan(Point::new(2, -4)), 9);
+        assert_eq!(Point3::new(-1, 0, 2).manhattan(Point3::ORIGIN), 3);
+    }
+
+    #[test]
+    fn steps_in_screen_coordinates() {
+        let origin = Point::ORIGIN;
+        assert_eq!(origin.step(Direction::Up), Point::new(0, -1));
+        assert_eq!(origin.step(Direction::Right), Point::new(1, 0));
+        assert_eq!(origin.step(Direction::Down), Point::new(0, 1));
+        assert_eq!(origin.step(Direction::Left), Point::new(-1, 0));
+        assert_eq!(origin.neighbours().count(), 4);
+        assert_eq!(
+            origin.neighbours_diagonal().collect::<Vec<_>>()[..3],
+            [Point::new(0, -1), Point::new(1, -1), Point::new(1, 0)]
+        );
+        assert_eq!(Point3::ORIGIN.neighbours().count(), 6);
+    }
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(
+                direction.offset() + direction.reverse().offset(),
+                Point::ORIGIN
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(
+            Direction::between(Point::new(2, 3), Point::new(2, 2)),
+            Some(Direction::Up)
+        );
+        assert_eq!(Direction::between(Point::new(2, 3), Point::new(3, 4)), None);
+
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.offset() + direction.reverse().offset(),
+                Point::ORIGIN
+            );
+        }
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert!(Direction8::DownLeft.is_diagonal());
+        assert!(!Direction8::from(Direction::Left).is_diagonal());
+    }
+
+    #[test]
+    fn parses_characters() {
+        assert_eq!(
+            "^>v<"
+                .chars()
+                .map(Direction::from_arrow)
+                .collect::<Vec<_>>(),
+            Direction::ALL.map(Some)
+        );
+        assert_eq!(
+            "URDL"
+                .chars()
+                .map(Direction::from_letter)
+                .collect::<Vec<_>>(),
+            Direction::ALL.map(Some)
+        );
+        assert_eq!(Direction::from_letter('W'), Some(Direction::Left));
+        assert_eq!(Direction::from_arrow('x'), None);
+        assert_eq!(Direction::Down.to_arrow(), 'v');
+    }
+}
@@ -0,0 +1,476 @@
+/// Weighted graphs over numbered nodes, with the algorithms puzzles keep asking for.
+/// Nodes are indices `0..len`; use an [`Interner`] to number nodes that come with names.
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use fxhash::FxHashMap;
+
+use super::Grid;
+
+/// A directed graph with weighted edges, stored as adjacency lists.
+/// Undirected graphs store every edge in both directions, see [`Graph::add_undirected_edge`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl Graph {
+    /// A graph of `nodes` nodes without edges.
+    #[must_use]
+    pub fn new(nodes: usize) -> Self {
+        Self {
+            edges: vec![Vec::new(); nodes],
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Add a node and return its index.
+    pub fn add_node(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.edges.len() - 1
+    }
+
+    /// Add an edge from `from` to `to`, growing the graph if either node does not exist yet.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64) {
+        let len = from.max(to) + 1;
+        if self.edges.len() < len {
+            self.edges.resize(len, Vec::new());
+        }
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: u64) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// Outgoing edges of `node` as `(neighbour, weight)`.
+    #[must_use]
+    pub fn neighbours(&self, node: usize) -> &[(usize, u64)] {
+        &self.edges[node]
+    }
+
+    /// Groups of nodes connected by edges in either direction, each sorted, ordered by their smallest node.
+    #[must_use]
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut parents: Vec<usize> = (0..self.len()).collect();
+        fn root(parents: &mut [usize], mut node: usize) -> usize {
+            while parents[node] != node {
+                parents[node] = parents[parents[node]];
+                node = parents[node];
+            }
+            node
+        }
+
+        for (a, edges) in self.edges.iter().enumerate() {
+            for &(b, _) in edges {
+                let (a, b) = (root(&mut parents, a), root(&mut parents, b));
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        let mut component_of = FxHashMap::default();
+        for node in 0..self.len() {
+            let root = root(&mut parents, node);
+            let index = *component_of.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(node);
+        }
+        components
+    }
+
+    /// Order nodes so that every edge points forward, preferring smaller nodes first. `None` if the graph has a cycle.
+    #[must_use]
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut incoming = vec![0; self.len()];
+        for &(to, _) in self.edges.iter().flatten() {
+            incoming[to] += 1;
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.len())
+            .filter(|&node| incoming[node] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(node);
+            for &(to, _) in &self.edges[node] {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Weight of the heaviest path from `start` to `end` that visits no node twice, `None` if `end` cannot be reached.
+    /// Tries every path, so it is only feasible on small graphs like compressed mazes.
+    ///
+    /// # Panics
+    /// If the graph has more than 128 nodes.
+    #[must_use]
+    pub fn longest_path(&self, start: usize, end: usize) -> Option<u64> {
+        assert!(
+            self.len() <= 128,
+            "longest path search is limited to 128 nodes, got {}",
+            self.len()
+        );
+
+        fn search(graph: &Graph, node: usize, end: usize, visited: u128) -> Option<u64> {
+            if node == end {
+                return Some(0);
+            }
+            let visited = visited | 1 << node;
+
+            graph.edges[node]
+                .iter()
+                .filter(|&&(next, _)| visited & 1 << next == 0)
+                .filter_map(|&(next, weight)| Some(weight + search(graph, next, end, visited)?))
+                .max()
+        }
+
+        search(self, start, end, 0)
+    }
+
+    /// Like [`Graph::longest_path`], but also returns the nodes of the path from `start` to `end`.
+    ///
+    /// # Panics
+    /// If the graph has more than 128 nodes.
+    #[must_use]
+    pub fn longest_route(&self, start: usize, end: usize) -> Option<(u64, Vec<usize>)> {
+        assert!(
+            self.len() <= 128,
+            "longest path search is limited to 128 nodes, got {}",
+            self.len()
+        );
+
+        fn search(
+            graph: &Graph,
+            (node, length): (usize, u64),
+            end: usize,
+            visited: u128,
+            route: &mut Vec<usize>,
+            best: &mut Option<(u64, Vec<usize>)>,
+        ) {
+            route.push(node);
+            if node == end {
+                if best.as_ref().is_none_or(|&(longest, _)| length > longest) {
+                    *best = Some((length, route.clone()));
+                }
+            } else {
+                let visited = visited | 1 << node;
+                for &(next, weight) in &graph.edges[node] {
+                    if visited & 1 << next == 0 {
+                        search(graph, (next, length + weight), end, visited, route, best);
+                    }
+                }
+            }
+            route.pop();
+        }
+
+        let mut best = None;
+        search(self, (start, 0), end, 0, &mut Vec::new(), &mut best);
+        best
+    }
+
+    /// Minimum cut with the Stoer–Wagner algorithm: the lowest total weight of edges whose removal splits the graph,
+    /// together with the nodes on one side of the cut. `None` for graphs with less than two nodes.
+    ///
+    /// The graph has to be undirected. Deterministic, unlike randomized contraction.
+    #[must_use]
+    pub fn min_cut(&self) -> Option<(u64, Vec<usize>)> {
+        if self.len() < 2 {
+            return None;
+        }
+
+        let mut adjacency: Vec<FxHashMap<usize, u64>> = vec![FxHashMap::default(); self.len()];
+        for (a, edges) in self.edges.iter().enumerate() {
+            for &(b, weight) in edges.iter().filter(|&&(b, _)| b != a) {
+                *adjacency[a].entry(b).or_default() += weight;
+            }
+        }
+
+        // nodes merged into each remaining node
+        let mut members: Vec<Vec<usize>> = (0..self.len()).map(|node| vec![node]).collect();
+        let mut active: Vec<usize> = (0..self.len()).collect();
+        let mut best: Option<(u64, Vec<usize>)> = None;
+
+        while active.len() > 1 {
+            // add the most tightly connected node until all are added, the last two are merged
+            let mut added = vec![false; self.len()];
+            let mut connection: FxHashMap<usize, u64> = FxHashMap::default();
+            let mut queue = BinaryHeap::from([(0, active[0])]);
+            let (mut s, mut t, mut cut) = (active[0], active[0], 0);
+            let mut count = 0;
+
+            while let Some((weight, node)) = queue.pop() {
+                if added[node] {
+                    continue;
+                }
+                added[node] = true;
+                count += 1;
+                (s, t, cut) = (t, node, weight);
+
+                for (&next, &weight) in &adjacency[node] {
+                    if !added[next] {
+                        let connection = connection.entry(next).or_default();
+                        *connection += weight;
+                        queue.push((*connection, next));
+                    }
+                }
+            }
+
+            if count < active.len() {
+                // not connected, nothing has to be cut
+                let side = active.iter().filter(|&&node| added[node]);
+                return Some((0, side.flat_map(|&node| members[node].clone()).collect()));
+            }
+
+            if best.as_ref().is_none_or(|(weight, _)| cut < *weight) {
+                best = Some((cut, members[t].clone()));
+            }
+
+            for (next, weight) in std::mem::take(&mut adjacency[t]) {
+                adjacency[next].remove(&t);
+                if next != s {
+                    *adjacency[s].entry(next).or_default() += weight;
+                    *adjacency[next].entry(s).or_default() += weight;
+                }
+            }
+            let merged = std::mem::take(&mut members[t]);
+            members[s].extend(merged);
+            active.retain(|&node| node != t);
+        }
+
+        best
+    }
+}
+
+/// Numbers labels in the order they are first seen, to use them as graph nodes.
+#[derive(Debug, Clone)]
+pub struct Interner<T> {
+    ids: FxHashMap<T, usize>,
+    labels: Vec<T>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            ids: FxHashMap::default(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> Interner<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of `label`, assigning the next free one if it is new.
+    pub fn intern(&mut self, label: T) -> usize {
+        if let Some(&id) = self.ids.get(&label) {
+            return id;
+        }
+        self.labels.push(label.clone());
+        self.ids.insert(label, self.labels.len() - 1);
+        self.labels.len() - 1
+    }
+
+    #[must_use]
+    pub fn get(&self, label: &T) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+
+    #[must_use]
+    pub fn label(&self, id: usize) -> &T {
+        &self.labels[id]
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+/// Contract the corridors of a maze into a graph of its junctions.
+///
+/// Cells that `open` accepts are walkable. Open cells with other than two open neighbours, like junctions, dead ends and
+/// entrances, become nodes, connected by edges weighted with the length of the corridor between them.
+/// `can_step(from, to)` can forbid steps between adjacent open cells, e.g. against a slope: edges are only added in
+/// the directions a corridor can be walked.
+///
+/// Returns the graph and the grid position of each node.
+pub fn compress_grid<T>(
+    grid: &Grid<T>,
+    open: impl Fn(&T) -> bool,
+    can_step: impl Fn((usize, usize), (usize, usize)) -> bool,
+) -> (Graph, Vec<(usize, usize)>) {
+    let open_neighbours = |position: (usize, usize)| {
+        grid.neighbours(position.0, position.1)
+            .filter(|&(x, y)| open(&grid[(x, y)]))
+            .collect::<Vec<_>>()
+    };
+
+    let positions: Vec<(usize, usize)> = grid
+        .iter()
+        .filter(|&(position, cell)| open(cell) && open_neighbours(position).len() != 2)
+        .map(|(position, _)| position)
+        .collect();
+    let nodes: FxHashMap<(usize, usize), usize> = positions
+        .iter()
+        .enumerate()
+        .map(|(node, &position)| (position, node))
+        .collect();
+
+    let mut graph = Graph::new(positions.len());
+    for (node, &start) in positions.iter().enumerate() {
+        'corridors: for first in open_neighbours(start) {
+            let (mut previous, mut current, mut length) = (start, first, 1);
+            if !can_step(previous, current) {
+                continue;
+            }
+
+            while !nodes.contains_key(&current) {
+                let next = open_neighbours(current)
+                    .into_iter()
+                    .find(|&next| next != previous)
+                    .expect("corridors have two open neighbours");
+                if !can_step(current, next) {
+                    continue 'corridors;
+                }
+                (previous, current, length) = (current, next, length + 1);
+            }
+
+            graph.add_edge(node, nodes[&current], length);
+        }
+    }
+
+    (graph, positions)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compress_grid, Graph, Interner};
+    use crate::utils::Grid;
+
+    /// Two triangles joined by a single edge of weight 1: 0-1-2 and 3-4-5, with 2-3.
+    fn dumbbell() -> Graph {
+        let mut graph = Graph::new(6);
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+            graph.add_undirected_edge(a, b, 5);
+        }
+        graph.add_undirected_edge(2, 3, 1);
+        graph
+    }
+
+    #[test]
+    fn builds_graphs() {
+        let mut graph = Graph::default();
+        graph.add_edge(0, 2, 7);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.neighbours(0), &[(2, 7)]);
+        assert_eq!(graph.add_node(), 3);
+    }
+
+    #[test]
+    fn finds_components() {
+        let mut graph = Graph::new(6);
+        graph.add_undirected_edge(0, 3, 1);
+        graph.add_edge(4, 1, 1);
+        graph.add_edge(5, 4, 1);
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![0, 3], vec![1, 4, 5], vec![2]]
+        );
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let mut graph = Graph::new(4);
+        graph.add_edge(3, 1, 1);
+        graph.add_edge(1, 0, 1);
+        graph.add_edge(2, 0, 1);
+        assert_eq!(graph.topological_sort(), Some(vec![2, 3, 1, 0]));
+
+        graph.add_edge(0, 3, 1);
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn finds_longest_path() {
+        let graph = dumbbell();
+        // 0 -> 1 -> 2 -> 3 -> 5 -> 4
+        assert_eq!(graph.longest_path(0, 4), Some(5 + 5 + 1 + 5 + 5));
+        assert_eq!(graph.longest_path(0, 0), Some(0));
+        assert_eq!(
+            graph.longest_route(0, 4),
+            Some((5 + 5 + 1 + 5 + 5, vec![0, 1, 2, 3, 5, 4]))
+        );
+
+        let mut directed = Graph::new(3);
+        directed.add_edge(0, 1, 1);
+        assert_eq!(directed.longest_path(0, 2), None);
+        assert_eq!(directed.longest_route(0, 2), None);
+    }
+
+    #[test]
+    fn cuts_minimum() {
+        let (weight, mut side) = dumbbell().min_cut().unwrap();
+        side.sort_unstable();
+        assert_eq!(weight, 1);
+        assert!(side == vec![0, 1, 2] || side == vec![3, 4, 5]);
+
+        let mut disconnected = Graph::new(3);
+        disconnected.add_undirected_edge(0, 1, 4);
+        assert_eq!(disconnected.min_cut().map(|(weight, _)| weight), Some(0));
+        assert_eq!(Graph::new(1).min_cut(), None);
+    }
+
+    #[test]
+    fn interns_labels() {
+        let mut interner = Interner::new();
+        assert_eq!(interner.intern("a"), 0);
+        assert_eq!(interner.intern("b"), 1);
+        assert_eq!(interner.intern("a"), 0);
+        assert_eq!(interner.get(&"b"), Some(1));
+        assert_eq!(interner.label(1), &"b");
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn compresses_corridors() {
+        let grid = Grid::parse("#.###\n#...#\n#.#.#\n#...#\n###.#\n", Some).unwrap();
+        let (graph, positions) = compress_grid(&grid, |&c| c == '.', |_, _| true);
+
+        // entrance, the two forks and the exit
+        assert_eq!(positions, vec![(1, 0), (1, 1), (3, 3), (3, 4)]);
+        assert_eq!(graph.neighbours(0), &[(1, 1)]);
+        assert_eq!(graph.neighbours(1), &[(0, 1), (2, 4), (2, 4)]);
+        assert_eq!(graph.longest_path(0, 3), Some(1 + 4 + 1));
+
+        // forbid walking up
+        let (graph, _) = compress_grid(&grid, |&c| c == '.', |from, to| to.1 >= from.1);
+        assert_eq!(graph.neighbours(3), &[]);
+    }
+}
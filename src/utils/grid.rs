@@ -0,0 +1,451 @@
+/// Dense two-dimensional grid, the shape most puzzle inputs come in.
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use super::geometry::Point;
+use crate::template::error::{Error, Result};
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all eight neighbours, clockwise starting from the top.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row. Positions are `(x, y)` with the origin at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its rows. Returns `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parse a grid from text with one row per line, converting each character with `cell`.
+    /// Characters that `cell` rejects and rows of a different length are reported with their position.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut lines = input.trim_end().lines().peekable();
+        let width = lines.peek().map_or(0, |line| line.chars().count());
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in lines {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::at(input, &line[i..], format!("unexpected character '{c}'"))
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            if row_width != width {
+                return Err(Error::at(
+                    input,
+                    line,
+                    format!("expected a row of length {width}, got {row_width}"),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` lies within the grid. Takes signed coordinates so that steps off the grid can be checked.
+    #[must_use]
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Access by [`Point`], which may lie off the grid.
+    #[must_use]
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.contains(point.x as isize, point.y as isize)
+            .then(|| &self[(point.x as usize, point.y as usize)])
+    }
+
+    pub fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point.x as isize, point.y as isize)
+            .then(|| &mut self[(point.x as usize, point.y as usize)])
+    }
+
+    /// Access with coordinates wrapping around the edges, as if the grid was repeated infinitely.
+    /// Returns `None` only for an empty grid, which has nothing to repeat.
+    #[must_use]
+    pub fn get_wrapping(&self, x: isize, y: isize) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        Some(&self[(x, y)])
+    }
+
+    /// Positions of the orthogonal neighbours of `(x, y)` that lie within the grid.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Positions of the orthogonal and diagonal neighbours of `(x, y)` that lie within the grid.
+    pub fn neighbours_diagonal(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS_8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            self.contains(x, y).then_some((x as usize, y as usize))
+        })
+    }
+
+    /// # Panics
+    /// If `y` is not below the height.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "y {y} out of bounds of height {}",
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a width of zero, which empty grids have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    /// If `x` is not below the width.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "x {x} out of bounds of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All cells together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    /// Position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| (i % self.width, i / self.width))
+    }
+
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of the given size with every cell set to `value`.
+    #[must_use]
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirror the grid along its main diagonal, turning rows into columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flat_map(|column| column.cloned()).collect(),
+        }
+    }
+
+    /// Rotate the grid by 90° clockwise.
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self
+                .columns()
+                .flat_map(|column| column.rev().cloned())
+                .collect(),
+        }
+    }
+
+    /// Rotate the grid by 90° counter-clockwise.
+    #[must_use]
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < self.width,
+            "x {x} out of bounds of width {}",
+            self.width
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            x < self.width,
+            "x {x} out of bounds of width {}",
+            self.width
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.at(point)
+            .unwrap_or_else(|| panic!("{point} out of bounds of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.at_mut(point)
+            .unwrap_or_else(|| panic!("{point} out of bounds of the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+    use crate::template::error::Error;
+    use crate::utils::geometry::Point;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some).unwrap()
+    }
+
+    #[test]
+    fn parses_text() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn reports_invalid_cells() {
+        let digits = Grid::parse("12\n3x\n", |c| c.to_digit(10));
+        assert_eq!(
+            digits,
+            Err(Error::Parser {
+                line: 2,
+                column: 2,
+                message: "unexpected character 'x'".into()
+            })
+        );
+    }
+
+    #[test]
+    fn reports_ragged_rows() {
+        let result = Grid::parse("ab\nc\n", Some);
+        assert!(matches!(result, Err(Error::Parser { line: 2, .. })));
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = grid();
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert!(grid.contains(0, 0));
+        assert!(!grid.contains(-1, 0));
+        assert!(!grid.contains(0, 2));
+        assert_eq!(grid.at(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.at(Point::new(-1, 0)), None);
+        assert_eq!(grid[Point::new(0, 1)], 'd');
+    }
+
+    #[test]
+    fn wraps_around() {
+        let grid = grid();
+        assert_eq!(grid.get_wrapping(-1, 0), Some(&'c'));
+        assert_eq!(grid.get_wrapping(3, 3), Some(&'d'));
+        assert_eq!(grid.get_wrapping(-4, -2), Some(&'c'));
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours(1, 1).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_diagonal(1, 0).collect::<Vec<_>>(),
+            vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "x 3 out of bounds of width 3")]
+    fn checks_column_bounds() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    fn finds_positions() {
+        let grid = grid();
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.position(|&c| c == 'z'), None);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+
+    #[test]
+    fn handles_empty_grids() {
+        let grid = Grid::parse("", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.get(0, 0), None);
+        assert_eq!(grid.get_wrapping(1, -1), None);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(Grid::<u8>::filled(2, 2, 0).to_string(), "00\n00\n");
+    }
+}
@@ -0,0 +1,258 @@
+/// Sets of integers stored as ranges, for puzzles that push whole ranges of values through a transformation instead of single values.
+use std::ops::{Range, RangeInclusive};
+
+/// A set of integers, stored as sorted, disjoint and non-adjacent half-open ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sort and merge arbitrary ranges, dropping empty ones.
+    fn normalized(mut ranges: Vec<Range<i64>>) -> Self {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<i64>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalized(ranges);
+    }
+
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    /// Number of integers in the set.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end.abs_diff(range.start))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(i)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    #[must_use]
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    #[must_use]
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // advance whichever range ends first, the other may still overlap the next one
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Values of `self` that are not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            // skip ranges of `other` that end before this one starts
+            while others.next_if(|o| o.end <= start).is_some() {}
+
+            for o in others.clone().take_while(|o| o.start < range.end) {
+                if o.start > start {
+                    ranges.push(start..o.start);
+                }
+                start = start.max(o.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Split into the values below `threshold` and the values at or above it.
+    #[must_use]
+    pub fn split_at(&self, threshold: i64) -> (Self, Self) {
+        let (mut below, mut above) = (Vec::new(), Vec::new());
+        for range in &self.ranges {
+            if range.end <= threshold {
+                below.push(range.clone());
+            } else if range.start >= threshold {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..threshold);
+                above.push(threshold..range.end);
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Add `offset` to every value.
+    #[must_use]
+    pub fn shift(&self, offset: i64) -> Self {
+        Self {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| range.start + offset..range.end + offset)
+                .collect(),
+        }
+    }
+
+    /// Map values piece by piece: values in the range of a piece are shifted by its offset, values outside all pieces stay as they are.
+    /// Where pieces overlap, the first one wins.
+    #[must_use]
+    pub fn map_piecewise(&self, pieces: impl IntoIterator<Item = (Range<i64>, i64)>) -> Self {
+        let mut remaining = self.clone();
+        let mut mapped = Vec::new();
+
+        for (range, offset) in pieces {
+            let piece = Self::from(range);
+            mapped.extend(remaining.intersection(&piece).shift(offset).ranges);
+            remaining = remaining.difference(&piece);
+        }
+
+        mapped.extend(remaining.ranges);
+        Self::normalized(mapped)
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> Self {
+        Self::normalized(vec![range])
+    }
+}
+
+impl From<RangeInclusive<i64>> for IntervalSet {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Self::from(*range.start()..*range.end() + 1)
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::IntervalSet;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+        ranges.iter().map(|&(start, end)| start..end).collect()
+    }
+
+    #[test]
+    fn normalizes_ranges() {
+        let set = set(&[(5, 8), (0, 2), (1, 3), (8, 9), (4, 4)]);
+        assert_eq!(set.ranges(), &[0..3, 5..9]);
+        assert_eq!(set.len(), 7);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(8)));
+        assert!(set.contains(2) && set.contains(5) && set.contains(8));
+        assert!(!set.contains(3) && !set.contains(9) && !set.contains(-1));
+        assert_eq!(IntervalSet::from(1..=3), IntervalSet::from(1..4));
+        assert!(IntervalSet::from(3..3).is_empty());
+    }
+
+    #[test]
+    fn inserts_ranges() {
+        let mut set = IntervalSet::new();
+        set.insert(10..20);
+        set.insert(0..5);
+        set.insert(4..11);
+        assert_eq!(set.ranges(), &[0..20]);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(a.union(&b).ranges(), &[0..40]);
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+        assert!(a.difference(&set(&[(-5, 50)])).is_empty());
+    }
+
+    #[test]
+    fn splits_at_threshold() {
+        let (below, above) = set(&[(0, 10), (20, 30)]).split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(above.ranges(), &[25..30]);
+
+        let (below, above) = set(&[(0, 10)]).split_at(0);
+        assert!(below.is_empty());
+        assert_eq!(above.ranges(), &[0..10]);
+    }
+
+    #[test]
+    fn maps_piecewise() {
+        // the seed-to-soil map of 2023 day 5
+        let pieces = [(98..100, -48), (50..98, 2)];
+        assert_eq!(
+            set(&[(79, 93), (55, 68)])
+                .map_piecewise(pieces.clone())
+                .ranges(),
+            &[57..70, 81..95]
+        );
+        assert_eq!(set(&[(40, 100)]).map_piecewise(pieces).ranges(), &[40..100]);
+        // the first piece wins where pieces overlap
+        assert_eq!(
+            set(&[(0, 4)])
+                .map_piecewise([(0..2, 10), (0..4, 20)])
+                .ranges(),
+            &[10..12, 22..24]
+        );
+    }
+}
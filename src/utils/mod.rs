@@ -0,0 +1,20 @@
+/// Building blocks shared by solutions, so that new days do not have to reinvent them.
+pub mod circuit;
+pub mod cycle;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod number_theory;
+pub mod parse;
+pub mod random;
+pub mod trace;
+pub mod visualize;
+
+pub use circuit::Circuit;
+pub use geometry::{Direction, Direction8, Point, Point3};
+pub use graph::Graph;
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use random::Rng;
+pub use visualize::Canvas;
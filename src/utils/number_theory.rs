@@ -0,0 +1,221 @@
+/// Greatest common divisors, least common multiples and modular arithmetic over primitive integers.
+/// Operations that can overflow return `None` instead of wrapping or panicking.
+use std::{
+    fmt::Debug,
+    ops::{Div, Mul, Neg, Rem, Sub},
+};
+
+/// Primitive integer types the functions of this module work with.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Absolute value, `None` for the minimum of a signed type.
+    fn checked_abs(self) -> Option<Self>;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+/// Integers that can be negative, needed for Bézout coefficients.
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    (unsigned: $($int:ty),+) => {$(
+        impl_integer!($int, |value| Some(value));
+    )+};
+    (signed: $($int:ty),+) => {$(
+        impl_integer!($int, <$int>::checked_abs);
+        impl SignedInteger for $int {}
+    )+};
+    ($int:ty, $abs:expr) => {
+        impl Integer for $int {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$int>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$int>::checked_mul(self, rhs)
+            }
+
+            fn checked_abs(self) -> Option<Self> {
+                let abs: fn(Self) -> Option<Self> = $abs;
+                abs(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$int>::rem_euclid(self, rhs)
+            }
+        }
+    };
+}
+
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0. `None` if it does not fit, which only happens when it
+/// is the absolute value of a signed minimum like `i64::MIN`.
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.checked_abs()
+}
+
+/// Least common multiple, never negative. `None` if it overflows.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // divide first, so that only a result that does not fit overflows
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// Least common multiple of all `values`, 1 if there are none. `None` if it overflows.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// Extended Euclidean algorithm: `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < T::ZERO {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and `modulus` are coprime.
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// Chinese Remainder Theorem: the smallest non-negative `x` with `x ≡ residue (mod modulus)` for
+/// every `(residue, modulus)` pair, together with the modulus of the combined congruence.
+///
+/// Moduli do not have to be coprime. Returns `None` if the congruences contradict each other or
+/// the combined modulus overflows.
+pub fn crt<T: SignedInteger>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), (r2, m2)| {
+            if m2 <= T::ZERO {
+                return None;
+            }
+
+            let g = gcd(m1, m2)?;
+            let difference = r2.rem_euclid(m2) - r1.rem_euclid(m2);
+            if difference % g != T::ZERO {
+                return None;
+            }
+
+            // x = r1 + m1 * k, where k solves m1 * k ≡ r2 - r1 (mod m2)
+            let m2_reduced = m2 / g;
+            let inverse = mod_inverse(m1 / g, m2_reduced)?;
+            let k = (difference / g)
+                .rem_euclid(m2_reduced)
+                .checked_mul(inverse)?
+                .rem_euclid(m2_reduced);
+
+            let modulus = (m1 / g).checked_mul(m2)?;
+            let x = m1.checked_mul(k)?.checked_add(r1)?.rem_euclid(modulus);
+            Some((x, modulus))
+        })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crt, extended_gcd, gcd, lcm, lcm_all, mod_inverse};
+
+    #[test]
+    fn computes_gcd() {
+        assert_eq!(gcd(12u64, 18), Some(6));
+        assert_eq!(gcd(18u8, 12), Some(6));
+        assert_eq!(gcd(7u32, 0), Some(7));
+        assert_eq!(gcd(0u32, 0), Some(0));
+        assert_eq!(gcd(-4i128, 6), Some(2));
+        assert_eq!(gcd(4i64, -6), Some(2));
+    }
+
+    #[test]
+    fn detects_gcd_overflow() {
+        assert_eq!(gcd(i64::MIN, 0), None);
+        assert_eq!(gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(gcd(i64::MIN, 6), Some(2));
+        assert_eq!(lcm(i64::MIN, 0), Some(0));
+        assert_eq!(lcm(i64::MIN, i64::MIN), None);
+    }
+
+    #[test]
+    fn computes_lcm() {
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(lcm(-4i32, 6), Some(12));
+        assert_eq!(lcm(0u8, 5), Some(0));
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all::<u64>([]), Some(1));
+    }
+
+    #[test]
+    fn detects_lcm_overflow() {
+        // the product overflows, but the result does not
+        assert_eq!(lcm(1u64 << 40, 1 << 41), Some(1 << 41));
+        assert_eq!(lcm(200u8, 100), Some(200));
+        assert_eq!(lcm(200u8, 3), None);
+        assert_eq!(lcm_all([u64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn computes_bezout_coefficients() {
+        for (a, b) in [(240i64, 46), (-240, 46), (46, -240), (0, 5), (17, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(Some(g), gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn computes_modular_inverse() {
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(6i64, 9), None);
+        assert_eq!(mod_inverse(3i64, 0), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // moduli need not be coprime
+        assert_eq!(crt([(2i64, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1i64, 5)]), Some((4, 5)));
+        assert_eq!(crt::<i64>([]), Some((0, 1)));
+        assert_eq!(crt([(0i8, 100), (0, 99)]), None);
+    }
+}
@@ -0,0 +1,291 @@
+/// Input-parsing combinators built on `nom`, for the shapes puzzle inputs keep coming in.
+/// Parsers return [`ParseResult`] so that `context` labels survive, and [`parse_all`] turns failures into
+/// positional parse errors.
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{
+        char, digit1, line_ending, multispace0, none_of, one_of, space0, space1,
+    },
+    combinator::{map, map_opt, map_res, opt, recognize},
+    error::{context, ContextError, ErrorKind, ParseError, VerboseError, VerboseErrorKind},
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+use super::{
+    geometry::{Point, Point3},
+    grid::Grid,
+};
+use crate::template::error::{Error, Result};
+
+pub type ParseResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// Run `parser` on the whole input, ignoring trailing whitespace.
+/// Failures and unconsumed input are reported at their position, with the `context` labels they occurred in.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> ParseResult<'a, T>,
+) -> Result<T> {
+    match parser(input.trim_end()) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(Error::at(input, rest, "unexpected trailing input")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(to_error(input, e)),
+        Err(nom::Err::Incomplete(_)) => Err(Error::at(
+            input,
+            &input[input.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+/// Report the innermost failure, named after the innermost `context` if there is one, and the contexts around it.
+fn to_error<'a>(input: &'a str, error: VerboseError<&'a str>) -> Error {
+    let Some(&(position, _)) = error.errors.first() else {
+        return Error::at(input, input, "invalid input");
+    };
+
+    let mut contexts = error.errors.iter().filter_map(|(_, kind)| match kind {
+        VerboseErrorKind::Context(label) => Some(*label),
+        _ => None,
+    });
+
+    let mut message = match (&error.errors[0].1, contexts.next()) {
+        (VerboseErrorKind::Char(c), _) => format!("expected {c:?}"),
+        (_, Some(label)) => format!("expected {label}"),
+        (VerboseErrorKind::Nom(kind), None) => {
+            format!("expected {}", kind.description().to_lowercase())
+        }
+        (VerboseErrorKind::Context(label), None) => format!("expected {label}"),
+    };
+    for label in contexts {
+        message += &format!(" in {label}");
+    }
+
+    Error::at(input, position, message)
+}
+
+/// A signed or unsigned integer, like `-12` or `+3`. Values that do not fit into `T` fail.
+pub fn integer<'a, T: FromStr>(input: &'a str) -> ParseResult<'a, T> {
+    context(
+        "integer",
+        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+    )(input)
+}
+
+/// Integers separated by spaces, commas or both, like `79 14 55` or `1, -2,3`.
+pub fn integers<'a, T: FromStr>(input: &'a str) -> ParseResult<'a, Vec<T>> {
+    separated_list1(alt((recognize(pair(char(','), space0)), space1)), integer)(input)
+}
+
+/// `label: value`, where the value may also start on the next line, like `seeds: 79 14` or `seed-to-soil map:\n50 98 2`.
+pub fn labelled<'a, O>(
+    label: &'static str,
+    value: impl FnMut(&'a str) -> ParseResult<'a, O>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, O> {
+    context(
+        label,
+        preceded(tuple((tag(label), char(':'), multispace0)), value),
+    )
+}
+
+/// `key: value` with a parsed key, like `Game 1: …` or `jqt: rhn xhk`.
+pub fn key_value<'a, K, V>(
+    key: impl FnMut(&'a str) -> ParseResult<'a, K>,
+    value: impl FnMut(&'a str) -> ParseResult<'a, V>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, (K, V)> {
+    separated_pair(key, terminated(char(':'), space0), value)
+}
+
+/// One or more lines of `line`.
+pub fn lines<'a, O>(
+    line: impl FnMut(&'a str) -> ParseResult<'a, O>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>> {
+    separated_list1(line_ending, line)
+}
+
+/// One or more blocks of `block`, separated by blank lines.
+pub fn blocks<'a, O>(
+    block: impl FnMut(&'a str) -> ParseResult<'a, O>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// `label{fields}`, like `px{a<2006:qkq,rfg}`.
+pub fn record<'a, L, O>(
+    label: impl FnMut(&'a str) -> ParseResult<'a, L>,
+    fields: impl FnMut(&'a str) -> ParseResult<'a, O>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, (L, O)> {
+    pair(label, delimited(char('{'), fields, char('}')))
+}
+
+/// Separator between coordinates: a comma, optionally followed by spaces.
+fn comma(input: &str) -> ParseResult<'_, char> {
+    terminated(char(','), space0)(input)
+}
+
+/// `x, y` or `x,y`.
+pub fn point(input: &str) -> ParseResult<'_, Point> {
+    context(
+        "point",
+        map(separated_pair(integer, comma, integer), |(x, y)| {
+            Point::new(x, y)
+        }),
+    )(input)
+}
+
+/// `x, y, z` or `x,y,z`.
+pub fn point3(input: &str) -> ParseResult<'_, Point3> {
+    context(
+        "point",
+        map(
+            tuple((integer, comma, integer, comma, integer)),
+            |(x, _, y, _, z)| Point3::new(x, y, z),
+        ),
+    )(input)
+}
+
+/// A block of equally long lines, converting each character with `cell`. Parsing stops at the first
+/// character `cell` rejects, so grids can be followed by other sections.
+pub fn grid<'a, T>(
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Grid<T>> {
+    move |input| {
+        let (rest, rows) =
+            separated_list1(line_ending, many1(map_opt(none_of("\r\n"), &mut cell)))(input)?;
+        match Grid::from_rows(rows) {
+            Some(grid) => Ok((rest, grid)),
+            None => Err(nom::Err::Failure(VerboseError::add_context(
+                input,
+                "rows of equal length",
+                VerboseError::from_error_kind(input, ErrorKind::Verify),
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+    use crate::utils::{Point, Point3};
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(parse_all("-12", integer::<i32>), Ok(-12));
+        assert_eq!(parse_all("+3", integer::<u8>), Ok(3));
+        assert_eq!(
+            parse_all("79 14 -55,13, 2\n", integers::<i64>),
+            Ok(vec![79, 14, -55, 13, 2])
+        );
+    }
+
+    #[test]
+    fn parses_sections() {
+        let input = "seeds: 1 2\n\nmap:\n3 4\n5 6";
+        let parsed = parse_all(
+            input,
+            separated_pair(
+                labelled("seeds", integers::<u32>),
+                pair(line_ending, line_ending),
+                labelled("map", lines(integers::<u32>)),
+            ),
+        );
+        assert_eq!(parsed, Ok((vec![1, 2], vec![vec![3, 4], vec![5, 6]])));
+
+        let parsed = parse_all(
+            "a: 1\nbc: 2 3",
+            lines(key_value(nom::character::complete::alpha1, integers::<u8>)),
+        );
+        assert_eq!(parsed, Ok(vec![("a", vec![1]), ("bc", vec![2, 3])]));
+    }
+
+    #[test]
+    fn parses_blocks_and_records() {
+        let parsed = parse_all("1\n2\n\n3\n", blocks(lines(integer::<u8>)));
+        assert_eq!(parsed, Ok(vec![vec![1, 2], vec![3]]));
+
+        let parsed = parse_all(
+            "px{1,2}",
+            record(
+                nom::character::complete::alpha1,
+                separated_list1(char(','), integer::<u8>),
+            ),
+        );
+        assert_eq!(parsed, Ok(("px", vec![1, 2])));
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!(parse_all("3,-4", point), Ok(Point::new(3, -4)));
+        assert_eq!(
+            parse_all("19, 13,  30", point3),
+            Ok(Point3::new(19, 13, 30))
+        );
+    }
+
+    #[test]
+    fn parses_grids() {
+        let parsed = parse_all(
+            "#.\n.#\n\n1",
+            separated_pair(
+                grid(|c| Some(c == '#')),
+                pair(line_ending, line_ending),
+                integer::<u8>,
+            ),
+        );
+        let (walls, number) = parsed.unwrap();
+        assert_eq!((walls.width(), walls.height(), number), (2, 2, 1));
+        assert!(walls[(0, 0)] && !walls[(1, 0)]);
+
+        assert_eq!(
+            parse_all("##\n#", grid(|c| Some(c == '#'))),
+            Err(Error::Parser {
+                line: 1,
+                column: 1,
+                message: "expected rows of equal length".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn reports_positions() {
+        assert_eq!(
+            parse_all("seeds: 1 x", labelled("seeds", integers::<u8>)),
+            Err(Error::Parser {
+                line: 1,
+                column: 9,
+                message: "unexpected trailing input".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_all(
+                "a\nseeds: x",
+                preceded(tag("a\n"), labelled("seeds", integers::<u8>))
+            ),
+            Err(Error::Parser {
+                line: 2,
+                column: 8,
+                message: "expected integer in seeds".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_all("(1, 2", delimited(char('('), point, char(')'))),
+            Err(Error::Parser {
+                line: 1,
+                column: 6,
+                message: "expected ')'".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_all("300", integer::<u8>),
+            Err(Error::Parser {
+                line: 1,
+                column: 1,
+                message: "expected integer".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,345 @@
+/// Step-by-step recordings of simulations, to watch how a solution arrives at its answer.
+///
+/// Solutions call [`frame`] with a picture of an intermediate state and [`event`] with a description of what just
+/// happened. Both take closures that only run while `cargo trace` records. Without the `trace` feature they compile
+/// to nothing, so benchmarks are not affected.
+use std::time::Duration;
+
+use fxhash::FxHashMap;
+
+use super::visualize::{Canvas, Color, PIXELS_PER_CELL};
+
+/// Whether this build can record traces.
+pub const ENABLED: bool = cfg!(feature = "trace");
+
+/// Entries after which a recording stops, so that long simulations do not run out of memory.
+pub const LIMIT: usize = 20_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Entry {
+    Frame(Canvas),
+    Event(String),
+}
+
+/// Events shown in the time of a frame when replaying.
+pub const EVENTS_PER_FRAME: u32 = 10;
+
+impl Entry {
+    /// How long to wait before showing this entry, when frames are `delay` apart.
+    pub fn delay(&self, delay: Duration) -> Duration {
+        match self {
+            Entry::Frame(_) => delay,
+            Entry::Event(_) => delay / EVENTS_PER_FRAME,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trace {
+    pub entries: Vec<Entry>,
+    /// Whether entries were dropped after reaching [`LIMIT`].
+    pub truncated: bool,
+}
+
+#[cfg(feature = "trace")]
+mod recorder {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    };
+
+    use super::{Entry, Trace, LIMIT};
+
+    static RECORDING: AtomicBool = AtomicBool::new(false);
+    static TRACE: Mutex<Trace> = Mutex::new(Trace {
+        entries: Vec::new(),
+        truncated: false,
+    });
+
+    pub fn start() {
+        *TRACE.lock().unwrap() = Trace::default();
+        RECORDING.store(true, Ordering::SeqCst);
+    }
+
+    pub fn stop() -> Trace {
+        RECORDING.store(false, Ordering::SeqCst);
+        std::mem::take(&mut *TRACE.lock().unwrap())
+    }
+
+    /// Record the entry `entry` creates, unless nothing is recording or the trace is full.
+    pub fn push(entry: impl FnOnce() -> Entry) {
+        if !RECORDING.load(Ordering::Relaxed) {
+            return;
+        }
+        let mut trace = TRACE.lock().unwrap();
+        if trace.entries.len() < LIMIT {
+            trace.entries.push(entry());
+        } else {
+            trace.truncated = true;
+        }
+    }
+}
+
+/// Record a picture of the current state.
+#[inline(always)]
+pub fn frame(canvas: impl FnOnce() -> Canvas) {
+    #[cfg(feature = "trace")]
+    recorder::push(|| Entry::Frame(canvas()));
+    #[cfg(not(feature = "trace"))]
+    let _ = canvas;
+}
+
+/// Record a description of what just happened.
+#[inline(always)]
+pub fn event(text: impl FnOnce() -> String) {
+    #[cfg(feature = "trace")]
+    recorder::push(|| Entry::Event(text()));
+    #[cfg(not(feature = "trace"))]
+    let _ = text;
+}
+
+/// Run `f` and return what it recorded. Without the `trace` feature the trace is always empty.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Trace) {
+    #[cfg(feature = "trace")]
+    {
+        recorder::start();
+        let result = f();
+        (result, recorder::stop())
+    }
+    #[cfg(not(feature = "trace"))]
+    (f(), Trace::default())
+}
+
+impl Trace {
+    pub fn frames(&self) -> impl Iterator<Item = &Canvas> {
+        self.entries.iter().filter_map(|entry| match entry {
+            Entry::Frame(canvas) => Some(canvas),
+            Entry::Event(_) => None,
+        })
+    }
+
+    /// An [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) recording that shows frames `delay` apart
+    /// and prints events [`EVENTS_PER_FRAME`] times as fast.
+    #[must_use]
+    pub fn to_asciicast(&self, delay: Duration) -> String {
+        let width = self.frames().map(Canvas::width).max().unwrap_or(80).max(80);
+        let height = self
+            .frames()
+            .map(Canvas::height)
+            .max()
+            .unwrap_or(24)
+            .max(24);
+
+        let mut cast = format!("{{\"version\": 2, \"width\": {width}, \"height\": {height}}}\n");
+        let mut time = 0.0;
+        for entry in &self.entries {
+            time += entry.delay(delay).as_secs_f64();
+            let output = match entry {
+                Entry::Frame(canvas) => format!("\x1b[H\x1b[2J{}", canvas.to_ansi()),
+                Entry::Event(text) => format!("{text}\n"),
+            };
+            let output = serde_json::to_string(&output.replace('\n', "\r\n"))
+                .expect("strings are always serializable");
+            cast += &format!("[{time:.3}, \"o\", {output}]\n");
+        }
+        cast
+    }
+
+    /// An animated GIF of the frames, `delay` apart. Events are left out.
+    /// Fails if the largest frame is wider or taller than the 65535 pixels a GIF can hold.
+    pub fn to_gif(&self, delay: Duration) -> Result<Vec<u8>, String> {
+        let scale = PIXELS_PER_CELL;
+        let width = self.frames().map(Canvas::width).max().unwrap_or(0) * scale;
+        let height = self.frames().map(Canvas::height).max().unwrap_or(0) * scale;
+        let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(format!(
+                "frames of {width}x{height} pixels do not fit into a GIF, which holds at most {0}x{0}",
+                u16::MAX
+            ));
+        };
+
+        // frames of different sizes are drawn at the top left of the largest
+        let frames: Vec<Vec<Color>> = self
+            .frames()
+            .map(|canvas| {
+                let mut pixels = vec![Color::BLACK; width * height];
+                let frame_width = canvas.width() * scale;
+                for (i, color) in canvas.pixels(scale).into_iter().enumerate() {
+                    pixels[i / frame_width * width + i % frame_width] = color;
+                }
+                pixels
+            })
+            .collect();
+        let (palette, index) = palette(frames.iter().flatten().copied());
+
+        let mut gif = b"GIF89a".to_vec();
+        gif.extend(gif_width.to_le_bytes());
+        gif.extend(gif_height.to_le_bytes());
+        // a global colour table of 256 colours
+        gif.extend([0xf7, 0, 0]);
+        for i in 0..256 {
+            let Color { r, g, b } = palette.get(i).copied().unwrap_or(Color::BLACK);
+            gif.extend([r, g, b]);
+        }
+        // loop forever
+        gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\0\0\0");
+
+        let delay = (delay.as_millis() / 10).min(u128::from(u16::MAX)) as u16;
+        for pixels in &frames {
+            gif.extend([0x21, 0xf9, 0x04, 0]);
+            gif.extend(delay.to_le_bytes());
+            gif.extend([0, 0]);
+
+            gif.push(0x2c);
+            gif.extend([0, 0, 0, 0]);
+            gif.extend(gif_width.to_le_bytes());
+            gif.extend(gif_height.to_le_bytes());
+            gif.push(0);
+
+            gif.push(8);
+            let data = lzw_uncompressed(pixels.iter().map(|color| index(*color)));
+            for block in data.chunks(255) {
+                gif.push(block.len() as u8);
+                gif.extend(block);
+            }
+            gif.push(0);
+        }
+
+        gif.push(0x3b);
+        Ok(gif)
+    }
+}
+
+/// Up to 256 colours and a lookup of the palette index of each colour. Images with more colours are reduced to
+/// 3 bits of red and green and 2 of blue.
+fn palette(colors: impl Iterator<Item = Color>) -> (Vec<Color>, impl Fn(Color) -> u8) {
+    let mut palette = Vec::new();
+    let mut indices = FxHashMap::default();
+    for color in colors {
+        indices.entry(color).or_insert_with(|| {
+            palette.push(color);
+            palette.len() - 1
+        });
+    }
+
+    let reduced = palette.len() > 256;
+    if reduced {
+        palette = (0..=255u8)
+            .map(|i| Color::rgb(i & 0xe0, (i << 3) & 0xe0, (i << 6) & 0xc0))
+            .collect();
+    }
+
+    let index = move |color: Color| {
+        if reduced {
+            (color.r & 0xe0) | (color.g & 0xe0) >> 3 | (color.b & 0xc0) >> 6
+        } else {
+            indices[&color] as u8
+        }
+    };
+    (palette, index)
+}
+
+/// GIF image data without compression: every pixel is a 9-bit code of its own, and the code table is cleared before
+/// it would grow past 9 bits.
+fn lzw_uncompressed(indices: impl Iterator<Item = u8>) -> Vec<u8> {
+    const CLEAR: u16 = 256;
+    const END: u16 = 257;
+
+    let mut bytes = Vec::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+    let mut emit = |code: u16| {
+        buffer |= u32::from(code) << bits;
+        bits += 9;
+        while bits >= 8 {
+            bytes.push(buffer as u8);
+            buffer >>= 8;
+            bits -= 8;
+        }
+    };
+
+    for (i, index) in indices.enumerate() {
+        // decoders add a table entry for every code after the first, codes grow to 10 bits at entry 512
+        if i % 253 == 0 {
+            emit(CLEAR);
+        }
+        emit(u16::from(index));
+    }
+    emit(END);
+
+    if bits > 0 {
+        bytes.push(buffer as u8);
+    }
+    bytes
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+    use crate::utils::Point;
+
+    fn trace() -> Trace {
+        let mut canvas = Canvas::new(2, 1);
+        canvas.highlight([Point::new(0, 0)], Color::RED);
+        Trace {
+            entries: vec![
+                Entry::Event("button -low-> broadcaster".into()),
+                Entry::Frame(canvas.clone()),
+                Entry::Frame(Canvas::new(1, 2)),
+            ],
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn records_only_with_the_feature() {
+        let (answer, trace) = record(|| {
+            frame(|| Canvas::new(1, 1));
+            event(|| "pulse".to_string());
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(trace.entries.len(), if ENABLED { 2 } else { 0 });
+
+        // nothing records outside of `record`
+        frame(|| panic!("frames are only drawn while recording"));
+    }
+
+    #[test]
+    fn writes_asciicasts() {
+        let cast = trace().to_asciicast(Duration::from_millis(500));
+        let lines: Vec<_> = cast.lines().collect();
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 80, \"height\": 24}");
+        assert_eq!(
+            lines[1],
+            "[0.050, \"o\", \"button -low-> broadcaster\\r\\n\"]"
+        );
+        assert!(lines[2].starts_with("[0.550, \"o\", \"\\u001b[H\\u001b[2J"));
+        assert!(lines[3].starts_with("[1.050, "));
+    }
+
+    #[test]
+    fn writes_gifs() {
+        let gif = trace().to_gif(Duration::from_millis(100)).unwrap();
+        assert!(gif.starts_with(b"GIF89a\x08\0\x08\0\xf7"));
+        assert_eq!(gif.last(), Some(&0x3b));
+        // red, then black for the empty cell and the padding
+        assert_eq!(gif[13..19], [0xe0, 0x40, 0x40, 0x10, 0x10, 0x18]);
+        assert_eq!(gif.windows(2).filter(|w| w == &[0x2c, 0]).count(), 2);
+    }
+
+    #[test]
+    fn refuses_gifs_too_large() {
+        let width = usize::from(u16::MAX) / PIXELS_PER_CELL + 1;
+        let trace = Trace {
+            entries: vec![Entry::Frame(Canvas::new(width, 1))],
+            truncated: false,
+        };
+        assert!(trace.to_gif(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn packs_nine_bit_codes() {
+        // clear (256), 1, end (257), least significant bits first
+        assert_eq!(lzw_uncompressed([1].into_iter()), [0x00, 0x03, 0x04, 0x04]);
+    }
+}
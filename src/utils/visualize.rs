@@ -0,0 +1,503 @@
+/// Pictures of grids, paths and regions, to debug wrong answers and to illustrate write-ups.
+///
+/// Solutions draw on a [`Canvas`] of cells: every cell has a glyph and optionally a colour, and paths are drawn on
+/// top of the cells. A canvas renders to the terminal with ANSI colours, to PPM or PNG images with a square of pixels
+/// per cell, or to SVG, where paths stay lines through the cell centres.
+use std::{fmt::Display, str::FromStr};
+
+use super::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0x10, 0x10, 0x18);
+    pub const GREY: Color = Color::rgb(0x80, 0x80, 0x88);
+    pub const WHITE: Color = Color::rgb(0xf0, 0xf0, 0xf0);
+    pub const RED: Color = Color::rgb(0xe0, 0x40, 0x40);
+    pub const GREEN: Color = Color::rgb(0x40, 0xc0, 0x60);
+    pub const BLUE: Color = Color::rgb(0x40, 0x80, 0xe0);
+    pub const YELLOW: Color = Color::rgb(0xf0, 0xd0, 0x40);
+
+    #[must_use]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Output formats of a [`Canvas`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Glyphs on coloured backgrounds, for the terminal.
+    #[default]
+    Ansi,
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    /// Format of a file name's extension, like `day10.svg`.
+    #[must_use]
+    pub fn from_extension(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        match extension {
+            "ppm" | "png" | "svg" => extension.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Format::Ansi),
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            "svg" => Ok(Format::Svg),
+            _ => Err(format!(
+                "unknown format \"{s}\", expected one of: ansi, ppm, png, svg"
+            )),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Ansi => write!(f, "ansi"),
+            Format::Ppm => write!(f, "ppm"),
+            Format::Png => write!(f, "png"),
+            Format::Svg => write!(f, "svg"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    glyph: char,
+    color: Option<Color>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Path {
+    points: Vec<Point>,
+    color: Color,
+    closed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    paths: Vec<Path>,
+}
+
+impl Canvas {
+    /// An empty canvas of `width × height` cells.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        let empty = Cell {
+            glyph: ' ',
+            color: None,
+        };
+        Self {
+            width,
+            height,
+            cells: vec![empty; width * height],
+            paths: Vec::new(),
+        }
+    }
+
+    /// A canvas with a cell for every cell of `grid`, showing the glyph `glyph` returns for it.
+    #[must_use]
+    pub fn from_grid<T>(grid: &super::Grid<T>, mut glyph: impl FnMut(&T) -> char) -> Self {
+        let mut canvas = Self::new(grid.width(), grid.height());
+        for ((x, y), cell) in grid.iter() {
+            canvas.set(x, y, glyph(cell));
+        }
+        canvas
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn cell_mut(&mut self, point: Point) -> Option<&mut Cell> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        self.cells.get_mut(y * self.width + x)
+    }
+
+    /// Show `glyph` in the cell at `(x, y)`. Cells outside the canvas are ignored.
+    pub fn set(&mut self, x: usize, y: usize, glyph: char) {
+        if let Some(cell) = self.cell_mut(Point::from((x, y))) {
+            cell.glyph = glyph;
+        }
+    }
+
+    /// Colour `cells`, e.g. the tiles of a region. Cells outside the canvas are ignored.
+    pub fn highlight(&mut self, cells: impl IntoIterator<Item = Point>, color: Color) {
+        for point in cells {
+            if let Some(cell) = self.cell_mut(point) {
+                cell.color = Some(color);
+            }
+        }
+    }
+
+    /// Draw a path through the centres of `points`, in straight lines between consecutive points.
+    pub fn path(&mut self, points: impl IntoIterator<Item = Point>, color: Color) {
+        self.paths.push(Path {
+            points: points.into_iter().collect(),
+            color,
+            closed: false,
+        });
+    }
+
+    /// Draw the outline of the polygon with corners `points`.
+    pub fn polygon(&mut self, points: impl IntoIterator<Item = Point>, color: Color) {
+        self.paths.push(Path {
+            points: points.into_iter().collect(),
+            color,
+            closed: true,
+        });
+    }
+
+    /// Cells with the colours of paths painted over them.
+    fn painted(&self) -> Vec<Cell> {
+        let mut colored = self.clone();
+        for path in &self.paths {
+            for point in path.cells() {
+                if let Some(cell) = colored.cell_mut(point) {
+                    cell.color = Some(path.color);
+                }
+            }
+        }
+        colored.cells
+    }
+
+    /// Colour of every cell, with paths drawn on top. Cells without a colour are grey unless they are empty,
+    /// that is their glyph is a space or a dot.
+    fn colors(&self) -> Vec<Color> {
+        self.painted()
+            .iter()
+            .map(|cell| match (cell.color, cell.glyph) {
+                (Some(color), _) => color,
+                (None, ' ' | '.') => Color::BLACK,
+                (None, _) => Color::GREY,
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn render(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Ansi => self.to_ansi().into_bytes(),
+            Format::Ppm => self.to_ppm(PIXELS_PER_CELL),
+            Format::Png => self.to_png(PIXELS_PER_CELL),
+            Format::Svg => self.to_svg().into_bytes(),
+        }
+    }
+
+    /// Glyphs with 24-bit colours: coloured cells and paths as backgrounds.
+    #[must_use]
+    pub fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+        for row in self.painted().chunks(self.width.max(1)) {
+            for cell in row {
+                match cell.color {
+                    Some(Color { r, g, b }) => {
+                        ansi += &format!(
+                            "\x1b[48;2;{r};{g};{b}m\x1b[38;2;0;0;0m{}\x1b[0m",
+                            cell.glyph
+                        )
+                    }
+                    None => ansi.push(cell.glyph),
+                }
+            }
+            ansi.push('\n');
+        }
+        ansi
+    }
+
+    /// Pixels in rows, top to bottom, with `scale × scale` pixels per cell.
+    #[must_use]
+    pub fn pixels(&self, scale: usize) -> Vec<Color> {
+        let colors = self.colors();
+        let mut pixels = Vec::with_capacity(self.width * self.height * scale * scale);
+        for row in colors.chunks(self.width.max(1)) {
+            for _ in 0..scale {
+                for &color in row {
+                    pixels.extend(std::iter::repeat_n(color, scale));
+                }
+            }
+        }
+        pixels
+    }
+
+    /// A binary PPM image with `scale × scale` pixels per cell.
+    #[must_use]
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let mut ppm =
+            format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale).into_bytes();
+        for Color { r, g, b } in self.pixels(scale) {
+            ppm.extend([r, g, b]);
+        }
+        ppm
+    }
+
+    /// An uncompressed PNG image with `scale × scale` pixels per cell.
+    #[must_use]
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.width * scale, self.height * scale);
+
+        // every row starts with its filter type, 0 for none
+        let mut raw = Vec::with_capacity((width * 3 + 1) * height);
+        for row in self.pixels(scale).chunks(width.max(1)) {
+            raw.push(0);
+            for &Color { r, g, b } in row {
+                raw.extend([r, g, b]);
+            }
+        }
+
+        let mut header = Vec::new();
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        // 8 bits per channel, RGB, deflate, no filters, not interlaced
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// An SVG image with coloured cells as squares and paths as lines.
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        let size = SVG_CELL_SIZE;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\">\n",
+            self.width * size,
+            self.height * size
+        );
+        svg += &format!(
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+            Color::BLACK
+        );
+
+        for (i, cell) in self.cells.iter().enumerate() {
+            let fill = match (cell.color, cell.glyph) {
+                (Some(color), _) => color,
+                (None, ' ' | '.') => continue,
+                (None, _) => Color::GREY,
+            };
+            let (x, y) = (i % self.width * size, i / self.width * size);
+            svg += &format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{size}\" height=\"{size}\" fill=\"{fill}\"/>\n"
+            );
+        }
+
+        for path in &self.paths {
+            let points = path
+                .points
+                .iter()
+                .map(|point| {
+                    let centre = |c: i64| c * size as i64 + size as i64 / 2;
+                    format!("{},{}", centre(point.x), centre(point.y))
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let element = if path.closed { "polygon" } else { "polyline" };
+            svg += &format!(
+                "<{element} points=\"{points}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"round\"/>\n",
+                path.color,
+                size / 3
+            );
+        }
+
+        svg += "</svg>\n";
+        svg
+    }
+}
+
+/// Pixels per cell of images rendered with [`Canvas::render`].
+pub const PIXELS_PER_CELL: usize = 4;
+/// Size of a cell in SVG units.
+const SVG_CELL_SIZE: usize = 10;
+
+impl Path {
+    /// Cells the path passes through.
+    fn cells(&self) -> Vec<Point> {
+        let mut cells = Vec::new();
+        let closing = self.closed.then(|| self.points.first()).flatten();
+        let corners: Vec<Point> = self.points.iter().chain(closing).copied().collect();
+
+        if let [only] = corners[..] {
+            cells.push(only);
+        }
+        for pair in corners.windows(2) {
+            cells.extend(line(pair[0], pair[1]));
+        }
+        cells
+    }
+}
+
+/// Cells on the line from `from` to `to`, both included, with Bresenham's algorithm.
+fn line(from: Point, to: Point) -> Vec<Point> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (step_x, step_y) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+
+    let mut cells = vec![from];
+    let (mut point, mut error) = (from, dx + dy);
+    while point != to {
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            point.x += step_x;
+        }
+        if doubled <= dx {
+            error += dx;
+            point.y += step_y;
+        }
+        cells.push(point);
+    }
+    cells
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// A zlib stream of uncompressed deflate blocks, which every PNG decoder reads.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const BLOCK: usize = 0xffff;
+
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = data.chunks(BLOCK).collect::<Vec<_>>();
+    for (i, block) in blocks.iter().enumerate() {
+        zlib.push(u8::from(i + 1 == blocks.len()));
+        let len = block.len() as u16;
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(*block);
+    }
+    if blocks.is_empty() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    zlib.extend((b << 16 | a).to_be_bytes());
+    zlib
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let mut canvas = Canvas::new(3, 2);
+        canvas.set(0, 0, '#');
+        canvas.highlight([Point::new(1, 1)], Color::BLUE);
+        canvas
+    }
+
+    #[test]
+    fn draws_lines() {
+        assert_eq!(
+            line(Point::new(0, 0), Point::new(3, 0)),
+            (0..4).map(|x| Point::new(x, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            line(Point::new(2, 2), Point::new(0, 0)),
+            vec![Point::new(2, 2), Point::new(1, 1), Point::new(0, 0)]
+        );
+
+        let mut canvas = canvas();
+        canvas.polygon(
+            [Point::new(0, 0), Point::new(2, 0), Point::new(2, 1)],
+            Color::RED,
+        );
+        let colors = canvas.colors();
+        assert_eq!(colors[..3], [Color::RED; 3]);
+        assert_eq!(colors[3..], [Color::BLACK, Color::BLUE, Color::RED]);
+    }
+
+    #[test]
+    fn renders_ansi() {
+        let ansi = canvas().to_ansi();
+        assert_eq!(
+            ansi,
+            "#  \n \x1b[48;2;64;128;224m\x1b[38;2;0;0;0m \x1b[0m \n"
+        );
+    }
+
+    #[test]
+    fn renders_images() {
+        let ppm = canvas().to_ppm(2);
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(ppm.len(), "P6\n6 4\n255\n".len() + 6 * 4 * 3);
+
+        let png = canvas().to_png(2);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x06\0\0\0\x04"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+        let svg = canvas().to_svg();
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"10\" height=\"10\" fill=\"#808088\"/>"));
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"10\" width=\"10\" height=\"10\" fill=\"#4080e0\"/>")
+        );
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("svg".parse(), Ok(Format::Svg));
+        assert_eq!(Format::from_extension("out/day10.png"), Some(Format::Png));
+        assert_eq!(Format::from_extension("day10.txt"), None);
+        assert!("gif".parse::<Format>().is_err());
+    }
+}